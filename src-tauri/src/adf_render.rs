@@ -0,0 +1,495 @@
+use serde_json::Value;
+
+// Atlassian Document Format → Markdown / 평문 변환
+// 이슈 상세를 앱에 표시하거나 중복 비교 등에 사용할 때 설명 전체를 읽어옵니다.
+
+#[derive(Clone, Copy, PartialEq)]
+enum Style {
+    Markdown,
+    Plain,
+}
+
+const INLINE_NODES: &[&str] = &[
+    "text",
+    "hardBreak",
+    "mention",
+    "emoji",
+    "date",
+    "status",
+    "inlineCard",
+    "placeholder",
+    "mediaInline",
+];
+
+pub fn adf_to_markdown(doc: &Value) -> String {
+    render_document(doc, Style::Markdown)
+}
+
+pub fn adf_to_plain_text(doc: &Value) -> String {
+    render_document(doc, Style::Plain)
+}
+
+fn render_document(doc: &Value, style: Style) -> String {
+    // v2 API 등 문자열로 저장된 설명은 그대로 반환
+    if let Some(text) = doc.as_str() {
+        return text.to_string();
+    }
+    render_blocks(children(doc), style, "\n\n").trim_end().to_string()
+}
+
+fn node_type(node: &Value) -> &str {
+    node["type"].as_str().unwrap_or("")
+}
+
+fn children(node: &Value) -> &[Value] {
+    node["content"].as_array().map(|c| c.as_slice()).unwrap_or(&[])
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    node["attrs"][name].as_str().filter(|s| !s.is_empty())
+}
+
+// 연속된 인라인 노드는 한 줄로 묶고 블록 사이는 separator로 구분
+fn render_blocks(nodes: &[Value], style: Style, separator: &str) -> String {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut inline_run: Vec<Value> = Vec::new();
+
+    for node in nodes {
+        if INLINE_NODES.contains(&node_type(node)) {
+            inline_run.push(node.clone());
+            continue;
+        }
+        if !inline_run.is_empty() {
+            blocks.push(render_inline(&inline_run, style));
+            inline_run.clear();
+        }
+        blocks.push(render_block(node, style));
+    }
+    if !inline_run.is_empty() {
+        blocks.push(render_inline(&inline_run, style));
+    }
+
+    blocks.retain(|b| !b.trim().is_empty());
+    blocks.join(separator)
+}
+
+// 첫 줄과 나머지 줄에 서로 다른 접두어를 붙입니다 (목록 표시, 들여쓰기, 인용)
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { first } else { rest };
+            if line.is_empty() {
+                prefix.trim_end().to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_block(node: &Value, style: Style) -> String {
+    let markdown = style == Style::Markdown;
+
+    match node_type(node) {
+        "paragraph" => render_inline(children(node), style),
+        "heading" => {
+            let text = render_inline(children(node), style);
+            if markdown {
+                let level = node["attrs"]["level"].as_u64().unwrap_or(1).clamp(1, 6) as usize;
+                format!("{} {}", "#".repeat(level), text)
+            } else {
+                text
+            }
+        }
+        "bulletList" => children(node)
+            .iter()
+            .map(|item| prefix_lines(&render_blocks(children(item), style, "\n"), "- ", "  "))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "orderedList" => {
+            let start = node["attrs"]["order"].as_u64().unwrap_or(1);
+            children(node)
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let marker = format!("{}. ", start + i as u64);
+                    let indent = " ".repeat(marker.len());
+                    prefix_lines(&render_blocks(children(item), style, "\n"), &marker, &indent)
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        "taskList" => children(node)
+            .iter()
+            .map(|item| match node_type(item) {
+                // 중첩된 체크리스트는 직전 항목 아래로 들여씁니다.
+                "taskList" => prefix_lines(&render_block(item, style), "  ", "  "),
+                _ => {
+                    let done = attr(item, "state") == Some("DONE");
+                    let marker = match (markdown, done) {
+                        (true, true) => "- [x] ",
+                        (true, false) => "- [ ] ",
+                        (false, true) => "[x] ",
+                        (false, false) => "[ ] ",
+                    };
+                    let indent = " ".repeat(marker.len());
+                    prefix_lines(&render_blocks(children(item), style, "\n"), marker, &indent)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "decisionList" => children(node)
+            .iter()
+            .map(|item| prefix_lines(&render_blocks(children(item), style, "\n"), "- ✔ ", "    "))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "codeBlock" => {
+            let code: String = children(node).iter().filter_map(|t| t["text"].as_str()).collect();
+            if !markdown {
+                return code;
+            }
+            // 코드 안에 ```가 있으면 더 긴 펜스를 사용
+            let fence = "`".repeat(longest_backtick_run(&code).max(2) + 1);
+            format!("{}{}\n{}\n{}", fence, attr(node, "language").unwrap_or(""), code, fence)
+        }
+        "blockquote" => {
+            let body = render_blocks(children(node), style, "\n\n");
+            if markdown {
+                prefix_lines(&body, "> ", "> ")
+            } else {
+                body
+            }
+        }
+        "panel" => {
+            let label = match attr(node, "panelType").unwrap_or("info") {
+                "note" => "노트",
+                "warning" => "경고",
+                "error" => "오류",
+                "success" => "성공",
+                _ => "정보",
+            };
+            let body = render_blocks(children(node), style, "\n\n");
+            if markdown {
+                prefix_lines(&format!("**{}**\n\n{}", label, body), "> ", "> ")
+            } else {
+                format!("[{}] {}", label, body)
+            }
+        }
+        "expand" | "nestedExpand" => {
+            let body = render_blocks(children(node), style, "\n\n");
+            match attr(node, "title") {
+                Some(title) if markdown => format!("**{}**\n\n{}", escape_markdown(title), body),
+                Some(title) => format!("{}\n{}", title, body),
+                None => body,
+            }
+        }
+        "rule" => "---".to_string(),
+        "table" => render_table(node, style),
+        "mediaSingle" | "mediaGroup" => children(node)
+            .iter()
+            .map(|media| render_media(media, style))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "media" => render_media(node, style),
+        "blockCard" | "embedCard" => match attr(node, "url") {
+            Some(url) if markdown => format!("<{}>", url),
+            Some(url) => url.to_string(),
+            None => String::new(),
+        },
+        "extension" | "inlineExtension" => format!("[확장: {}]", attr(node, "extensionKey").unwrap_or("unknown")),
+        // layoutSection, layoutColumn, bodiedExtension, decisionItem 등 컨테이너는 내용만 출력
+        _ => render_blocks(children(node), style, "\n\n"),
+    }
+}
+
+fn render_media(node: &Value, style: Style) -> String {
+    let alt = attr(node, "alt");
+    match (attr(node, "type"), attr(node, "url")) {
+        (Some("external"), Some(url)) if style == Style::Markdown => format!("![{}]({})", alt.unwrap_or(""), url),
+        (Some("external"), Some(url)) => url.to_string(),
+        _ => match alt {
+            Some(alt) => format!("[첨부 파일: {}]", alt),
+            None => "[첨부 파일]".to_string(),
+        },
+    }
+}
+
+fn render_table(node: &Value, style: Style) -> String {
+    let rows: Vec<Vec<String>> = children(node)
+        .iter()
+        .map(|row| {
+            children(row)
+                .iter()
+                .map(|cell| {
+                    let text = render_blocks(children(cell), style, "\n");
+                    match style {
+                        Style::Markdown => text.replace('|', "\\|").replace('\n', "<br>"),
+                        Style::Plain => text.replace('\n', " "),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    if style == Style::Plain {
+        return rows.iter().map(|cells| cells.join(" | ")).collect::<Vec<_>>().join("\n");
+    }
+
+    // Markdown 표는 머리글 행이 필요하므로 첫 행을 머리글로 사용
+    let columns = rows.iter().map(|cells| cells.len()).max().unwrap_or(0);
+    if columns == 0 {
+        return String::new();
+    }
+    let format_row = |cells: &[String]| {
+        let mut padded: Vec<&str> = cells.iter().map(|c| c.as_str()).collect();
+        padded.resize(columns, "");
+        format!("| {} |", padded.join(" | "))
+    };
+
+    let mut lines = vec![format_row(&rows[0]), format!("|{}", " --- |".repeat(columns))];
+    lines.extend(rows[1..].iter().map(|cells| format_row(cells)));
+    lines.join("\n")
+}
+
+fn render_inline(nodes: &[Value], style: Style) -> String {
+    let markdown = style == Style::Markdown;
+
+    nodes
+        .iter()
+        .map(|node| match node_type(node) {
+            "text" => render_text(node, style),
+            "hardBreak" => "\n".to_string(),
+            "mention" => {
+                let name = attr(node, "text").or_else(|| attr(node, "id")).unwrap_or("unknown");
+                if name.starts_with('@') {
+                    name.to_string()
+                } else {
+                    format!("@{}", name)
+                }
+            }
+            "emoji" => attr(node, "text")
+                .or_else(|| attr(node, "shortName"))
+                .unwrap_or("")
+                .to_string(),
+            "date" => attr(node, "timestamp")
+                .and_then(|ts| ts.parse::<i64>().ok())
+                .and_then(chrono::DateTime::from_timestamp_millis)
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            "status" => {
+                let text = attr(node, "text").unwrap_or("").to_uppercase();
+                if markdown {
+                    format!("\\[{}\\]", escape_markdown(&text))
+                } else {
+                    format!("[{}]", text)
+                }
+            }
+            "inlineCard" => match attr(node, "url") {
+                Some(url) if markdown => format!("<{}>", url),
+                Some(url) => url.to_string(),
+                None => String::new(),
+            },
+            "mediaInline" => render_media(node, style),
+            "placeholder" => String::new(),
+            _ => render_inline(children(node), style),
+        })
+        .collect()
+}
+
+fn render_text(node: &Value, style: Style) -> String {
+    let text = node["text"].as_str().unwrap_or("");
+    let marks = node["marks"].as_array().map(|m| m.as_slice()).unwrap_or(&[]);
+    let find_mark = |kind: &str| marks.iter().find(|m| node_type(m) == kind);
+    let href = find_mark("link").and_then(|link| link["attrs"]["href"].as_str());
+
+    if style == Style::Plain {
+        return match href {
+            Some(href) if href != text => format!("{} ({})", text, href),
+            _ => text.to_string(),
+        };
+    }
+
+    let mut body = if find_mark("code").is_some() {
+        code_span(text)
+    } else {
+        escape_markdown(text)
+    };
+    for (kind, delimiter) in [("strike", "~~"), ("em", "*"), ("strong", "**")] {
+        if find_mark(kind).is_some() {
+            body = wrap(&body, delimiter);
+        }
+    }
+    match href {
+        Some(href) => format!("[{}]({})", body, href),
+        None => body,
+    }
+}
+
+// 앞뒤 공백은 서식 기호 밖으로 빼야 Markdown에서 인식됩니다.
+fn wrap(text: &str, delimiter: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+    format!("{}{}{}{}{}", leading, delimiter, trimmed, delimiter, trailing)
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(|run| run.len()).max().unwrap_or(0)
+}
+
+fn code_span(text: &str) -> String {
+    let fence = "`".repeat(longest_backtick_run(text) + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{} {} {}", fence, text, fence)
+    } else {
+        format!("{}{}{}", fence, text, fence)
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '~' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn text(value: &str) -> Value {
+        json!({ "type": "text", "text": value })
+    }
+
+    fn marked(value: &str, marks: Value) -> Value {
+        json!({ "type": "text", "text": value, "marks": marks })
+    }
+
+    fn paragraph(content: Vec<Value>) -> Value {
+        json!({ "type": "paragraph", "content": content })
+    }
+
+    fn list_item(content: Vec<Value>) -> Value {
+        json!({ "type": "listItem", "content": content })
+    }
+
+    fn doc(content: Vec<Value>) -> Value {
+        json!({ "type": "doc", "version": 1, "content": content })
+    }
+
+    #[test]
+    fn renders_markdown_and_plain_text() {
+        // (문서, Markdown, 평문)
+        let cases = [
+            (
+                doc(vec![paragraph(vec![
+                    marked("굵게", json!([{ "type": "strong" }])),
+                    text(" 그리고 "),
+                    marked("링크", json!([{ "type": "link", "attrs": { "href": "https://x.io" } }])),
+                ])]),
+                "**굵게** 그리고 [링크](https://x.io)",
+                "굵게 그리고 링크 (https://x.io)",
+            ),
+            (
+                doc(vec![paragraph(vec![
+                    marked("기울임", json!([{ "type": "em" }])),
+                    text(" "),
+                    marked("취소", json!([{ "type": "strike" }])),
+                    text(" "),
+                    marked("code", json!([{ "type": "code" }])),
+                ])]),
+                "*기울임* ~~취소~~ `code`",
+                "기울임 취소 code",
+            ),
+            (doc(vec![paragraph(vec![text("a_b*c [x]")])]), "a\\_b\\*c \\[x\\]", "a_b*c [x]"),
+            (
+                doc(vec![json!({ "type": "heading", "attrs": { "level": 2 }, "content": [text("제목")] })]),
+                "## 제목",
+                "제목",
+            ),
+            (
+                doc(vec![json!({ "type": "bulletList", "content": [
+                    list_item(vec![
+                        paragraph(vec![text("하나")]),
+                        json!({ "type": "bulletList", "content": [list_item(vec![paragraph(vec![text("둘")])])] }),
+                    ]),
+                    list_item(vec![paragraph(vec![text("셋")])]),
+                ] })]),
+                "- 하나\n  - 둘\n- 셋",
+                "- 하나\n  - 둘\n- 셋",
+            ),
+            (
+                doc(vec![json!({ "type": "orderedList", "attrs": { "order": 3 }, "content": [
+                    list_item(vec![paragraph(vec![text("셋째")])]),
+                    list_item(vec![paragraph(vec![text("넷째")])]),
+                ] })]),
+                "3. 셋째\n4. 넷째",
+                "3. 셋째\n4. 넷째",
+            ),
+            (
+                doc(vec![json!({ "type": "taskList", "content": [
+                    { "type": "taskItem", "attrs": { "state": "DONE" }, "content": [text("완료")] },
+                    { "type": "taskItem", "attrs": { "state": "TODO" }, "content": [text("할 일")] },
+                ] })]),
+                "- [x] 완료\n- [ ] 할 일",
+                "[x] 완료\n[ ] 할 일",
+            ),
+            (
+                doc(vec![json!({ "type": "codeBlock", "attrs": { "language": "rust" }, "content": [text("fn main() {}")] })]),
+                "```rust\nfn main() {}\n```",
+                "fn main() {}",
+            ),
+            (
+                doc(vec![json!({ "type": "table", "content": [
+                    { "type": "tableRow", "content": [
+                        { "type": "tableHeader", "content": [paragraph(vec![text("A")])] },
+                        { "type": "tableHeader", "content": [paragraph(vec![text("B")])] },
+                    ] },
+                    { "type": "tableRow", "content": [
+                        { "type": "tableCell", "content": [paragraph(vec![text("1")])] },
+                        { "type": "tableCell", "content": [paragraph(vec![text("2|3")])] },
+                    ] },
+                ] })]),
+                "| A | B |\n| --- | --- |\n| 1 | 2\\|3 |",
+                "A | B\n1 | 2|3",
+            ),
+            (
+                doc(vec![json!({ "type": "panel", "attrs": { "panelType": "warning" }, "content": [paragraph(vec![text("주의")])] })]),
+                "> **경고**\n>\n> 주의",
+                "[경고] 주의",
+            ),
+            (
+                doc(vec![paragraph(vec![
+                    json!({ "type": "mention", "attrs": { "id": "abc", "text": "@홍길동" } }),
+                    text(" 확인"),
+                    json!({ "type": "hardBreak" }),
+                    text("다음 줄"),
+                ])]),
+                "@홍길동 확인\n다음 줄",
+                "@홍길동 확인\n다음 줄",
+            ),
+            (
+                doc(vec![paragraph(vec![text("첫 문단")]), json!({ "type": "rule" }), paragraph(vec![text("둘째 문단")])]),
+                "첫 문단\n\n---\n\n둘째 문단",
+                "첫 문단\n\n---\n\n둘째 문단",
+            ),
+            (json!("이미 문자열인 설명"), "이미 문자열인 설명", "이미 문자열인 설명"),
+            (doc(vec![]), "", ""),
+        ];
+
+        for (document, markdown, plain) in cases {
+            assert_eq!(adf_to_markdown(&document), markdown, "문서: {}", document);
+            assert_eq!(adf_to_plain_text(&document), plain, "문서: {}", document);
+        }
+    }
+}
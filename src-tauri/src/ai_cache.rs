@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::IssueAnalysis;
+
+// 동일한 분석 요청의 응답을 디스크에 캐시합니다.
+// 키는 마스킹된 텍스트로 만들지만 분석 결과는 그대로 저장하므로,
+// 마스킹(redaction)이 꺼져 있으면 민감 정보가 캐시 파일에 남을 수 있습니다.
+const CACHE_DIR: &str = "cache/ai_analysis";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AICacheConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_ttl_seconds")]
+    pub ttl_seconds: u64,
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+    // 캐시 파일 전체 크기 제한 (바이트)
+    #[serde(default = "default_max_total_bytes")]
+    pub max_total_bytes: u64,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_ttl_seconds() -> u64 {
+    7 * 24 * 60 * 60
+}
+
+fn default_max_entries() -> usize {
+    500
+}
+
+fn default_max_total_bytes() -> u64 {
+    50 * 1024 * 1024
+}
+
+impl Default for AICacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            ttl_seconds: default_ttl_seconds(),
+            max_entries: default_max_entries(),
+            max_total_bytes: default_max_total_bytes(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    created_at: i64,
+    model: String,
+    prompt_version: u32,
+    analysis: IssueAnalysis,
+}
+
+// 공백 차이는 같은 요청으로 취급
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// 프롬프트에 들어가는 few-shot 예시도 키에 포함 (예시가 바뀌면 다른 요청)
+pub fn cache_key(text: &str, examples: &str, model: &str, temperature: f32, prompt_version: u32) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(normalize_text(text).as_bytes());
+    hasher.update([0]);
+    hasher.update(examples.as_bytes());
+    hasher.update([0]);
+    hasher.update(model.as_bytes());
+    hasher.update([0]);
+    hasher.update(format!("{:.3}", temperature).as_bytes());
+    hasher.update([0]);
+    hasher.update(prompt_version.to_le_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn entry_path(key: &str) -> PathBuf {
+    Path::new(CACHE_DIR).join(format!("{}.json", key))
+}
+
+pub fn load(config: &AICacheConfig, key: &str) -> Option<IssueAnalysis> {
+    if !config.enabled {
+        return None;
+    }
+
+    let path = entry_path(key);
+    let content = fs::read_to_string(&path).ok()?;
+    let entry: CacheEntry = match serde_json::from_str(&content) {
+        Ok(entry) => entry,
+        Err(e) => {
+            println!("AI 캐시 항목 손상, 삭제합니다 ({:?}): {}", path, e);
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+    };
+
+    let age = chrono::Utc::now().timestamp() - entry.created_at;
+    if age < 0 || age as u64 > config.ttl_seconds {
+        let _ = fs::remove_file(&path);
+        return None;
+    }
+
+    touch(&path);
+    Some(entry.analysis)
+}
+
+// 캐시 적중 시 수정 시각을 갱신해 prune이 최근에 쓰인 항목을 남기도록 함
+fn touch(path: &Path) {
+    let result = fs::File::options()
+        .append(true)
+        .open(path)
+        .and_then(|file| file.set_modified(std::time::SystemTime::now()));
+    if let Err(e) = result {
+        println!("AI 캐시 항목 사용 시각 갱신 실패 ({:?}): {}", path, e);
+    }
+}
+
+pub fn store(config: &AICacheConfig, key: &str, model: &str, prompt_version: u32, analysis: IssueAnalysis) -> Result<(), String> {
+    if !config.enabled || config.max_entries == 0 || config.max_total_bytes == 0 {
+        return Ok(());
+    }
+
+    fs::create_dir_all(CACHE_DIR).map_err(|e| format!("AI 캐시 디렉토리 생성 실패: {}", e))?;
+
+    let entry = CacheEntry {
+        created_at: chrono::Utc::now().timestamp(),
+        model: model.to_string(),
+        prompt_version,
+        analysis,
+    };
+    let content = serde_json::to_string(&entry).map_err(|e| format!("AI 캐시 직렬화 실패: {}", e))?;
+    fs::write(entry_path(key), content).map_err(|e| format!("AI 캐시 저장 실패: {}", e))?;
+
+    prune(Path::new(CACHE_DIR), config.max_entries, config.max_total_bytes)
+}
+
+// 최대 개수나 전체 크기를 넘으면 가장 오래 쓰이지 않은 항목부터 삭제 (수정 시각 = 마지막 저장/적중 시각)
+fn prune(dir: &Path, max_entries: usize, max_total_bytes: u64) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("AI 캐시 디렉토리 읽기 실패: {}", e))?;
+
+    let mut files: Vec<(std::time::SystemTime, u64, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("json"))
+        .filter_map(|path| {
+            let metadata = fs::metadata(&path).ok()?;
+            Some((metadata.modified().ok()?, metadata.len(), path))
+        })
+        .collect();
+
+    let mut count = files.len();
+    let mut total_bytes: u64 = files.iter().map(|(_, len, _)| len).sum();
+    if count <= max_entries && total_bytes <= max_total_bytes {
+        return Ok(());
+    }
+
+    files.sort_by_key(|(modified, _, _)| *modified);
+    for (_, len, path) in files {
+        if count <= max_entries && total_bytes <= max_total_bytes {
+            break;
+        }
+        if let Err(e) = fs::remove_file(&path) {
+            eprintln!("AI 캐시 항목 삭제 실패 ({:?}): {}", path, e);
+            continue;
+        }
+        count -= 1;
+        total_bytes -= len;
+    }
+
+    Ok(())
+}
+
+pub fn clear() -> Result<usize, String> {
+    if !Path::new(CACHE_DIR).exists() {
+        return Ok(0);
+    }
+
+    let entries = fs::read_dir(CACHE_DIR).map_err(|e| format!("AI 캐시 디렉토리 읽기 실패: {}", e))?;
+    let mut removed = 0;
+    for entry in entries {
+        let path = entry.map_err(|e| format!("디렉토리 항목 읽기 실패: {}", e))?.path();
+        if path.is_file() {
+            fs::remove_file(&path).map_err(|e| format!("AI 캐시 항목 삭제 실패: {}", e))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key_ignores_whitespace_but_not_examples() {
+        let key = cache_key("로그인  버그\n", "", "gemini-2.5-flash", 0.2, 1);
+        assert_eq!(key, cache_key("로그인 버그", "", "gemini-2.5-flash", 0.2, 1));
+        assert_ne!(key, cache_key("로그인 버그", "\n\n예시 1", "gemini-2.5-flash", 0.2, 1));
+        assert_ne!(key, cache_key("로그인 버그", "", "gemini-2.5-pro", 0.2, 1));
+        assert_ne!(key, cache_key("로그인 버그", "", "gemini-2.5-flash", 0.7, 1));
+        assert_ne!(key, cache_key("로그인 버그", "", "gemini-2.5-flash", 0.2, 2));
+    }
+
+    #[test]
+    fn prune_removes_oldest_entries_over_byte_limit() {
+        let dir = std::env::temp_dir().join(format!("pengu_ai_cache_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["a", "b", "c"] {
+            fs::write(dir.join(format!("{}.json", name)), "x".repeat(100)).unwrap();
+            // 수정 시각 순서가 구분되도록 잠시 대기
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+
+        prune(&dir, 10, 250).unwrap();
+
+        assert!(!dir.join("a.json").exists());
+        assert!(dir.join("b.json").exists());
+        assert!(dir.join("c.json").exists());
+
+        prune(&dir, 1, u64::MAX).unwrap();
+        assert!(!dir.join("b.json").exists());
+        assert!(dir.join("c.json").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn prune_keeps_recently_hit_entries() {
+        let dir = std::env::temp_dir().join(format!("pengu_ai_cache_lru_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["a", "b", "c"] {
+            fs::write(dir.join(format!("{}.json", name)), "x").unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+
+        // 가장 먼저 저장된 a가 적중하면 b가 가장 오래 쓰이지 않은 항목
+        touch(&dir.join("a.json"));
+        prune(&dir, 2, u64::MAX).unwrap();
+
+        assert!(dir.join("a.json").exists());
+        assert!(!dir.join("b.json").exists());
+        assert!(dir.join("c.json").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
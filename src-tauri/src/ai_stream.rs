@@ -0,0 +1,161 @@
+use serde::Serialize;
+
+// 스트리밍 분석 중 프론트엔드로 전달하는 이벤트 이름
+pub const ANALYSIS_DELTA_EVENT: &str = "ai-analysis-delta";
+pub const ANALYSIS_DONE_EVENT: &str = "ai-analysis-done";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalysisDelta {
+    // 이번에 새로 도착한 텍스트
+    pub delta: String,
+    // 지금까지 누적된 전체 텍스트
+    pub text: String,
+}
+
+// Server-Sent Events 파서
+// 네트워크 청크는 이벤트 경계나 UTF-8 문자 중간에서 잘릴 수 있으므로 바이트 단위로 버퍼링합니다.
+#[derive(Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    // 구분자를 이미 찾아본 위치 (청크마다 버퍼 처음부터 다시 찾지 않도록)
+    scanned: usize,
+}
+
+impl SseParser {
+    // 완성된 이벤트의 data 값들을 반환합니다.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        while let Some((end, separator_len)) = find_event_boundary(&self.buffer, self.scanned) {
+            let raw: Vec<u8> = self.buffer.drain(..end + separator_len).take(end).collect();
+            self.scanned = 0;
+            if let Some(data) = parse_event_data(&String::from_utf8_lossy(&raw)) {
+                events.push(data);
+            }
+        }
+        // 구분자가 청크 경계에 걸쳐 있을 수 있으므로 마지막 3바이트는 다음 청크와 함께 다시 확인
+        self.scanned = self.buffer.len().saturating_sub(3);
+        events
+    }
+
+    // 스트림 종료 시 구분자 없이 남아있는 마지막 이벤트 처리
+    pub fn finish(&mut self) -> Option<String> {
+        let raw = std::mem::take(&mut self.buffer);
+        self.scanned = 0;
+        parse_event_data(&String::from_utf8_lossy(&raw))
+    }
+}
+
+fn find_event_boundary(buffer: &[u8], from: usize) -> Option<(usize, usize)> {
+    (from..buffer.len()).find_map(|i| {
+        if buffer[i..].starts_with(b"\r\n\r\n") {
+            Some((i, 4))
+        } else if buffer[i..].starts_with(b"\n\n") {
+            Some((i, 2))
+        } else {
+            None
+        }
+    })
+}
+
+fn parse_event_data(raw: &str) -> Option<String> {
+    let data: Vec<&str> = raw
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|value| value.strip_prefix(' ').unwrap_or(value))
+        .collect();
+
+    if data.is_empty() {
+        None
+    } else {
+        Some(data.join("\n"))
+    }
+}
+
+// 스트림 이벤트 JSON에서 텍스트 조각 추출
+// Gemini(candidates[].content.parts[].text)와 OpenAI 호환(choices[].delta.content) 형식을 지원합니다.
+pub fn extract_delta_text(event: &serde_json::Value) -> Option<String> {
+    if let Some(parts) = event["candidates"][0]["content"]["parts"].as_array() {
+        return Some(parts.iter().filter_map(|part| part["text"].as_str()).collect());
+    }
+
+    event["choices"][0]["delta"]["content"]
+        .as_str()
+        .map(|text| text.to_string())
+}
+
+// 스트림 이벤트의 종료 사유 (Gemini: finishReason, OpenAI 호환: finish_reason)
+pub fn extract_finish_reason(event: &serde_json::Value) -> Option<String> {
+    event["candidates"][0]["finishReason"]
+        .as_str()
+        .or_else(|| event["choices"][0]["finish_reason"].as_str())
+        .map(|reason| reason.to_string())
+}
+
+// OpenAI 호환 서버의 스트림 종료 표시
+pub fn is_done_marker(data: &str) -> bool {
+    data.trim() == "[DONE]"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_events_split_across_chunks() {
+        let stream = "data: {\"a\":\"가나\"}\r\n\r\ndata: 둘\n\n: 주석\n\ndata: 셋\ndata: 넷";
+        let bytes = stream.as_bytes();
+
+        // 한 바이트씩 보내도 구분자와 UTF-8 문자가 잘리지 않아야 함
+        let mut parser = SseParser::default();
+        let mut events: Vec<String> = bytes.chunks(1).flat_map(|chunk| parser.push(chunk)).collect();
+        events.extend(parser.finish());
+        assert_eq!(events, ["{\"a\":\"가나\"}", "둘", "셋\n넷"]);
+
+        let mut parser = SseParser::default();
+        let mut whole = parser.push(bytes);
+        whole.extend(parser.finish());
+        assert_eq!(whole, events);
+    }
+
+    #[test]
+    fn extracts_gemini_text_and_finish_reason() {
+        let event = serde_json::json!({
+            "candidates": [{
+                "content": { "parts": [{ "text": "안녕" }, { "text": "하세요" }] },
+                "finishReason": "STOP"
+            }]
+        });
+        assert_eq!(extract_delta_text(&event).as_deref(), Some("안녕하세요"));
+        assert_eq!(extract_finish_reason(&event).as_deref(), Some("STOP"));
+        assert_eq!(extract_delta_text(&serde_json::json!({ "usageMetadata": {} })), None);
+    }
+
+    #[test]
+    fn parses_openai_compatible_stream() {
+        let stream = concat!(
+            "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\"}}]}\n\n",
+            "data: {\"choices\":[{\"delta\":{\"content\":\"{\\\"title\\\": \"}}]}\n\n",
+            "data: {\"choices\":[{\"delta\":{\"content\":\"\\\"로그인\\\"}\"},\"finish_reason\":\"stop\"}]}\n\n",
+            "data: [DONE]\n\n",
+        );
+
+        let mut parser = SseParser::default();
+        let events: Vec<String> = stream.as_bytes().chunks(7).flat_map(|chunk| parser.push(chunk)).collect();
+        assert_eq!(parser.finish(), None);
+        assert_eq!(events.len(), 4);
+        assert!(is_done_marker(&events[3]));
+
+        let mut text = String::new();
+        let mut finish_reason = None;
+        for data in events.iter().filter(|data| !is_done_marker(data)) {
+            let event: serde_json::Value = serde_json::from_str(data).unwrap();
+            text.push_str(&extract_delta_text(&event).unwrap_or_default());
+            finish_reason = extract_finish_reason(&event).or(finish_reason);
+        }
+        assert_eq!(text, "{\"title\": \"로그인\"}");
+        assert_eq!(finish_reason.as_deref(), Some("stop"));
+        assert!(!is_done_marker("{\"done\": true}"));
+    }
+}
@@ -0,0 +1,191 @@
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+// AI 호출별 토큰 사용량과 예상 비용 기록
+const USAGE_DIR: &str = "usage";
+const USAGE_FILE: &str = "ai_usage.jsonl";
+
+// 100만 토큰당 USD 가격
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+// 설정에 가격이 없을 때 사용하는 기본 가격표 (모델명 접두사 기준)
+const DEFAULT_PRICES: &[(&str, f64, f64)] = &[
+    ("gemini-2.5-pro", 1.25, 10.0),
+    ("gemini-2.5-flash-lite", 0.10, 0.40),
+    ("gemini-2.5-flash", 0.30, 2.50),
+    ("gemini-2.0-flash-lite", 0.075, 0.30),
+    ("gemini-2.0-flash", 0.10, 0.40),
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub timestamp: DateTime<Utc>,
+    pub model: String,
+    pub prompt_tokens: u64,
+    pub output_tokens: u64,
+    pub estimated_cost: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsageSummary {
+    pub date: String,
+    pub model: String,
+    pub calls: u64,
+    pub prompt_tokens: u64,
+    pub output_tokens: u64,
+    pub estimated_cost: f64,
+}
+
+// 가장 긴 접두사가 일치하는 가격 (예: gemini-2.5-flash-preview-05-20 → gemini-2.5-flash)
+fn longest_prefix<'a>(model: &str, prices: impl Iterator<Item = (&'a str, f64, f64)>) -> Option<ModelPrice> {
+    prices
+        .filter(|(name, _, _)| model.starts_with(name))
+        .max_by_key(|(name, _, _)| name.len())
+        .map(|(_, input, output)| ModelPrice {
+            input_per_million: input,
+            output_per_million: output,
+        })
+}
+
+fn find_price(model: &str, prices: &HashMap<String, ModelPrice>) -> Option<ModelPrice> {
+    if let Some(price) = prices.get(model) {
+        return Some(price.clone());
+    }
+
+    // 설정한 가격이 하나라도 일치하면 기본 가격표보다 우선
+    let configured = prices
+        .iter()
+        .map(|(name, price)| (name.as_str(), price.input_per_million, price.output_per_million));
+    longest_prefix(model, configured).or_else(|| longest_prefix(model, DEFAULT_PRICES.iter().copied()))
+}
+
+// Gemini usageMetadata로부터 사용량 기록
+pub fn record(model: &str, usage_metadata: &serde_json::Value, prices: &HashMap<String, ModelPrice>) -> Result<(), String> {
+    if !usage_metadata.is_object() {
+        return Ok(());
+    }
+
+    let prompt_tokens = usage_metadata["promptTokenCount"].as_u64().unwrap_or(0);
+    // 사고(thinking) 토큰도 출력 토큰으로 과금됩니다.
+    let output_tokens = usage_metadata["candidatesTokenCount"].as_u64().unwrap_or(0)
+        + usage_metadata["thoughtsTokenCount"].as_u64().unwrap_or(0);
+
+    let estimated_cost = match find_price(model, prices) {
+        Some(price) => {
+            (prompt_tokens as f64 * price.input_per_million + output_tokens as f64 * price.output_per_million) / 1_000_000.0
+        }
+        None => {
+            println!("'{}' 모델의 가격 정보가 없어 비용을 0으로 기록합니다.", model);
+            0.0
+        }
+    };
+
+    let record = UsageRecord {
+        timestamp: Utc::now(),
+        model: model.to_string(),
+        prompt_tokens,
+        output_tokens,
+        estimated_cost,
+    };
+
+    fs::create_dir_all(USAGE_DIR).map_err(|e| format!("사용량 디렉토리 생성 실패: {}", e))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(Path::new(USAGE_DIR).join(USAGE_FILE))
+        .map_err(|e| format!("사용량 파일 열기 실패: {}", e))?;
+
+    let line = serde_json::to_string(&record).map_err(|e| format!("사용량 직렬화 실패: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("사용량 기록 실패: {}", e))
+}
+
+// 날짜(로컬 시간)와 모델별로 집계
+pub fn summarize(from: Option<NaiveDate>, to: Option<NaiveDate>) -> Result<Vec<UsageSummary>, String> {
+    let path = Path::new(USAGE_DIR).join(USAGE_FILE);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let file = fs::File::open(&path).map_err(|e| format!("사용량 파일 열기 실패: {}", e))?;
+    let mut totals: BTreeMap<(NaiveDate, String), UsageSummary> = BTreeMap::new();
+
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| format!("사용량 파일 읽기 실패: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record: UsageRecord = match serde_json::from_str(&line) {
+            Ok(record) => record,
+            Err(e) => {
+                println!("잘못된 사용량 기록을 건너뜁니다: {}", e);
+                continue;
+            }
+        };
+
+        let date = record.timestamp.with_timezone(&Local).date_naive();
+        if from.is_some_and(|from| date < from) || to.is_some_and(|to| date > to) {
+            continue;
+        }
+
+        let summary = totals
+            .entry((date, record.model.clone()))
+            .or_insert_with(|| UsageSummary {
+                date: date.format("%Y-%m-%d").to_string(),
+                model: record.model.clone(),
+                calls: 0,
+                prompt_tokens: 0,
+                output_tokens: 0,
+                estimated_cost: 0.0,
+            });
+        summary.calls += 1;
+        summary.prompt_tokens += record.prompt_tokens;
+        summary.output_tokens += record.output_tokens;
+        summary.estimated_cost += record.estimated_cost;
+    }
+
+    Ok(totals.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(input: f64, output: f64) -> ModelPrice {
+        ModelPrice {
+            input_per_million: input,
+            output_per_million: output,
+        }
+    }
+
+    #[test]
+    fn configured_price_overrides_default_of_same_prefix() {
+        let prices = HashMap::from([("gemini-2.5-flash".to_string(), price(1.0, 2.0))]);
+        let found = find_price("gemini-2.5-flash-preview-05-20", &prices).unwrap();
+        assert_eq!(found.input_per_million, 1.0);
+        assert_eq!(found.output_per_million, 2.0);
+    }
+
+    #[test]
+    fn configured_prefix_wins_over_longer_default() {
+        let prices = HashMap::from([("gemini-2.5".to_string(), price(5.0, 6.0))]);
+        let found = find_price("gemini-2.5-flash-lite", &prices).unwrap();
+        assert_eq!(found.input_per_million, 5.0);
+    }
+
+    #[test]
+    fn falls_back_to_longest_default_prefix() {
+        let found = find_price("gemini-2.5-flash-lite-001", &HashMap::new()).unwrap();
+        assert_eq!(found.input_per_million, 0.10);
+        assert_eq!(found.output_per_million, 0.40);
+        assert!(find_price("gpt-4o", &HashMap::new()).is_none());
+    }
+}
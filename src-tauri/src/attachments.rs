@@ -0,0 +1,234 @@
+use base64::Engine;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::redact::Redactor;
+
+// AI 분석 요청에 함께 보내는 첨부 파일 (스크린샷, 로그 파일)
+const MAX_ATTACHMENTS: usize = 10;
+// 이미지/PDF 한 개의 최대 크기
+const MAX_BINARY_BYTES: u64 = 7 * 1024 * 1024;
+// Gemini 인라인 데이터는 요청 전체 20MB 제한이 있으므로 base64 증가분을 고려하여 제한
+const MAX_TOTAL_BYTES: u64 = 14 * 1024 * 1024;
+// 로그 파일이 길면 오류가 주로 찍히는 뒷부분만 보냅니다.
+const MAX_TEXT_CHARS: usize = 100_000;
+// 뒷부분만 읽을 때의 바이트 수 (UTF-8 한 글자는 최대 4바이트)
+const MAX_TEXT_BYTES: u64 = MAX_TEXT_CHARS as u64 * 4;
+// 형식 판별에 필요한 앞부분 크기
+const SIGNATURE_BYTES: usize = 12;
+
+pub const TEXT_EXTENSIONS: &[&str] = &["log", "txt", "json", "csv", "md", "xml", "yaml", "yml", "out", "err", "trace"];
+
+pub enum Attachment {
+    Binary { name: String, mime_type: String, data: Vec<u8> },
+    Text { name: String, content: String },
+}
+
+// 파일 시그니처로 이미지/PDF 종류 판별
+pub fn detect_binary_mime(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        Some("image/png")
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else if bytes.starts_with(b"%PDF") {
+        Some("application/pdf")
+    } else {
+        None
+    }
+}
+
+// 뒤에서부터 MAX_TEXT_CHARS자만 남김 (skipped_bytes: 읽지 않고 건너뛴 앞부분 크기)
+fn keep_tail(content: String, skipped_bytes: u64) -> String {
+    let total = content.chars().count();
+    if total <= MAX_TEXT_CHARS && skipped_bytes == 0 {
+        return content;
+    }
+    let tail: String = content.chars().skip(total.saturating_sub(MAX_TEXT_CHARS)).collect();
+    let omitted_bytes = skipped_bytes + (content.len() - tail.len()) as u64;
+    format!("...(앞부분 {}바이트 생략)\n{}", omitted_bytes, tail)
+}
+
+fn read_signature(file: &mut fs::File) -> std::io::Result<Vec<u8>> {
+    let mut signature = Vec::with_capacity(SIGNATURE_BYTES);
+    file.take(SIGNATURE_BYTES as u64).read_to_end(&mut signature)?;
+    Ok(signature)
+}
+
+// 파일 전체를 읽지 않고 끝에서 MAX_TEXT_BYTES만 읽음 (잘린 UTF-8 글자 앞부분은 버림)
+fn read_tail(file: &mut fs::File, len: u64) -> std::io::Result<(Vec<u8>, u64)> {
+    let start = len.saturating_sub(MAX_TEXT_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::with_capacity((len - start) as usize);
+    file.take(MAX_TEXT_BYTES).read_to_end(&mut bytes)?;
+
+    let skip = if start > 0 {
+        bytes.iter().take(3).take_while(|b| (**b & 0xC0) == 0x80).count()
+    } else {
+        0
+    };
+    bytes.drain(..skip);
+    Ok((bytes, start + skip as u64))
+}
+
+pub fn load(paths: &[String]) -> Result<Vec<Attachment>, String> {
+    if paths.len() > MAX_ATTACHMENTS {
+        return Err(format!("첨부 파일은 최대 {}개까지 가능합니다.", MAX_ATTACHMENTS));
+    }
+
+    let mut attachments = Vec::new();
+    let mut total_bytes = 0;
+
+    for path_str in paths {
+        let path = Path::new(path_str);
+        let name = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or(path_str)
+            .to_string();
+
+        let metadata = fs::metadata(path).map_err(|e| format!("첨부 파일을 찾을 수 없습니다 ({}): {}", name, e))?;
+        if !metadata.is_file() {
+            return Err(format!("첨부 대상이 파일이 아닙니다: {}", name));
+        }
+
+        let read_error = |e: std::io::Error| format!("첨부 파일 읽기 실패 ({}): {}", name, e);
+        let mut file = fs::File::open(path).map_err(read_error)?;
+        let signature = read_signature(&mut file).map_err(read_error)?;
+
+        // 크기는 내용을 읽기 전에 확인
+        if let Some(mime_type) = detect_binary_mime(&signature) {
+            if metadata.len() > MAX_BINARY_BYTES {
+                return Err(format!(
+                    "첨부 파일이 너무 큽니다 ({}): 최대 {}MB까지 허용됩니다",
+                    name,
+                    MAX_BINARY_BYTES / 1024 / 1024
+                ));
+            }
+            let mut bytes = signature;
+            file.read_to_end(&mut bytes).map_err(read_error)?;
+            total_bytes += bytes.len() as u64;
+            attachments.push(Attachment::Binary {
+                name,
+                mime_type: mime_type.to_string(),
+                data: bytes,
+            });
+            continue;
+        }
+
+        let extension = path
+            .extension()
+            .and_then(|s| s.to_str())
+            .map(|s| s.to_lowercase())
+            .unwrap_or_default();
+        let (bytes, skipped_bytes) = read_tail(&mut file, metadata.len()).map_err(read_error)?;
+        let content = match String::from_utf8(bytes) {
+            Ok(content) => content,
+            Err(e) if TEXT_EXTENSIONS.contains(&extension.as_str()) => String::from_utf8_lossy(e.as_bytes()).to_string(),
+            Err(_) => return Err(format!("지원하지 않는 첨부 파일 형식입니다: {}", name)),
+        };
+
+        let content = keep_tail(content, skipped_bytes);
+        total_bytes += content.len() as u64;
+        attachments.push(Attachment::Text { name, content });
+    }
+
+    if total_bytes > MAX_TOTAL_BYTES {
+        return Err(format!(
+            "첨부 파일 전체 크기가 너무 큽니다: 최대 {}MB까지 허용됩니다",
+            MAX_TOTAL_BYTES / 1024 / 1024
+        ));
+    }
+
+    Ok(attachments)
+}
+
+// Gemini 요청 parts로 변환 (텍스트 첨부는 본문과 동일하게 마스킹)
+pub fn to_parts(attachments: &[Attachment], redactor: &mut Redactor) -> Vec<serde_json::Value> {
+    attachments
+        .iter()
+        .map(|attachment| match attachment {
+            Attachment::Binary { name, mime_type, data } => {
+                println!("첨부 파일 추가: {} ({}, {} bytes)", name, mime_type, data.len());
+                serde_json::json!({
+                    "inline_data": {
+                        "mime_type": mime_type,
+                        "data": base64::engine::general_purpose::STANDARD.encode(data)
+                    }
+                })
+            }
+            Attachment::Text { name, content } => {
+                println!("첨부 로그 추가: {} ({}자)", name, content.chars().count());
+                serde_json::json!({
+                    "text": format!("첨부 파일 ({}):\n```\n{}\n```", name, redactor.redact(content))
+                })
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, bytes: &[u8]) -> String {
+        let path = std::env::temp_dir().join(format!("pengu_attachment_{}_{}", std::process::id(), name));
+        fs::write(&path, bytes).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn large_log_keeps_only_tail() {
+        let mut log = "가".repeat(MAX_TEXT_CHARS * 2);
+        log.push_str("\nERROR 마지막 줄");
+        let path = temp_file("large.log", log.as_bytes());
+
+        let attachments = load(std::slice::from_ref(&path)).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        match &attachments[0] {
+            Attachment::Text { content, .. } => {
+                assert!(content.starts_with("...(앞부분 "));
+                assert!(content.ends_with("\nERROR 마지막 줄"));
+                let body = content.split_once('\n').unwrap().1;
+                assert_eq!(body.chars().count(), MAX_TEXT_CHARS);
+            }
+            Attachment::Binary { .. } => panic!("텍스트 첨부여야 합니다"),
+        }
+    }
+
+    #[test]
+    fn small_text_is_unchanged() {
+        let path = temp_file("small.txt", "짧은 로그".as_bytes());
+        let attachments = load(std::slice::from_ref(&path)).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        match &attachments[0] {
+            Attachment::Text { content, .. } => assert_eq!(content, "짧은 로그"),
+            Attachment::Binary { .. } => panic!("텍스트 첨부여야 합니다"),
+        }
+    }
+
+    #[test]
+    fn oversized_image_is_rejected() {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        png.resize(MAX_BINARY_BYTES as usize + 1, 0);
+        let path = temp_file("large.png", &png);
+
+        let result = load(std::slice::from_ref(&path));
+        fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn detects_binary_signatures() {
+        assert_eq!(detect_binary_mime(b"%PDF-1.7"), Some("application/pdf"));
+        assert_eq!(detect_binary_mime(b"GIF89a"), Some("image/gif"));
+        assert_eq!(detect_binary_mime(b"RIFF\0\0\0\0WEBP"), Some("image/webp"));
+        assert_eq!(detect_binary_mime(b"plain text"), None);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::IssueAnalysis;
+
+// AI를 사용할 수 없을 때(API 키 없음, 네트워크 장애, 폐쇄망) 사용하는 로컬 분류기
+// analyze_with_ai 프롬프트의 분석 기준을 그대로 키워드 규칙으로 옮긴 것입니다.
+
+const EPIC_KEYWORDS: &[&str] = &[
+    "epic", "overhaul", "redesign", "migration", "roadmap", "multiple features", "platform",
+    "에픽", "대규모", "전면", "개편", "리뉴얼", "마이그레이션", "여러 기능", "로드맵", "프로젝트 전체",
+];
+
+const BUG_KEYWORDS: &[&str] = &[
+    "bug", "error", "exception", "crash", "fail", "broken", "not working", "doesn't work", "does not work",
+    "stack trace", "traceback", "panic", "regression", "failure", "500 error", "http 500", "error 500",
+    "버그", "오류", "에러", "예외", "크래시", "실패", "깨짐", "깨져", "안됨", "안 됨", "안돼", "안 돼", "작동하지",
+    "동작하지", "멈춤", "멈춰", "먹통", "튕김", "튕기", "튕깁", "튕겨", "꺼짐", "꺼져",
+];
+
+const STORY_KEYWORDS: &[&str] = &[
+    "feature", "add", "new", "support", "allow", "would like", "as a user", "enable",
+    "기능", "추가", "신규", "새로운", "지원", "요청", "원합니다", "했으면", "하고 싶", "개선",
+];
+
+const TASK_KEYWORDS: &[&str] = &[
+    "update", "upgrade", "refactor", "cleanup", "clean up", "document", "configure", "setup", "set up", "check",
+    "업데이트", "업그레이드", "리팩토링", "정리", "문서", "설정", "점검", "확인", "작업",
+];
+
+const CRITICAL_KEYWORDS: &[&str] = &[
+    "critical", "outage", "production down", "data loss", "security", "immediately", "asap", "p0", "sev1",
+    "치명", "장애", "서비스 중단", "데이터 유실", "보안", "즉시", "당장", "최우선",
+];
+
+const HIGH_KEYWORDS: &[&str] = &[
+    "urgent", "important", "high", "blocker", "blocking", "soon", "deadline",
+    "긴급", "급함", "급해", "시급", "중요", "빨리", "빠르게", "마감", "블로커",
+];
+
+const LOW_KEYWORDS: &[&str] = &[
+    "minor", "typo", "cosmetic", "trivial", "nice to have", "low", "someday", "simple",
+    "사소", "오타", "간단", "여유", "나중에", "단순",
+];
+
+const LABEL_KEYWORDS: &[(&str, &[&str])] = &[
+    ("ui", &["ui", "ux", "button", "screen", "layout", "css", "화면", "버튼", "디자인", "레이아웃"]),
+    ("api", &["api", "endpoint", "request", "response", "http", "엔드포인트", "요청", "응답"]),
+    ("performance", &["slow", "performance", "latency", "memory", "timeout", "느림", "느려", "성능", "지연", "메모리", "타임아웃"]),
+    ("security", &["security", "auth", "login", "password", "token", "permission", "보안", "인증", "로그인", "비밀번호", "권한"]),
+    ("database", &["database", "db", "sql", "query", "데이터베이스", "쿼리"]),
+    ("documentation", &["docs", "documentation", "readme", "문서"]),
+];
+
+const MAX_TITLE_CHARS: usize = 50;
+
+pub fn classify(text: &str) -> IssueAnalysis {
+    let normalized = text.to_lowercase();
+
+    let (issue_type, type_confidence) = classify_issue_type(&normalized);
+    let (priority, priority_confidence) = classify_priority(&normalized);
+
+    let mut labels: Vec<String> = LABEL_KEYWORDS
+        .iter()
+        .filter(|(_, keywords)| count_hits(&normalized, keywords) > 0)
+        .map(|(label, _)| label.to_string())
+        .collect();
+    labels.push("offline-analysis".to_string());
+
+    // 두 판단의 평균을 최종 신뢰도로 사용합니다.
+    let confidence = ((type_confidence + priority_confidence) / 2.0 * 100.0).round() / 100.0;
+
+    IssueAnalysis {
+        title: make_title(text),
+        description: text.trim().to_string(),
+        issue_type: issue_type.to_string(),
+        priority: priority.to_string(),
+        labels,
+        confidence: Some(confidence),
+        sections: None,
+    }
+}
+
+fn classify_issue_type(text: &str) -> (&'static str, f32) {
+    let candidates = [
+        ("Epic", count_hits(text, EPIC_KEYWORDS)),
+        ("Bug", count_hits(text, BUG_KEYWORDS)),
+        ("Story", count_hits(text, STORY_KEYWORDS)),
+        ("Task", count_hits(text, TASK_KEYWORDS)),
+    ];
+
+    // 동점이면 프롬프트 기준 순서(Epic > Bug > Story > Task)를 따릅니다.
+    let (best, best_hits) = candidates
+        .iter()
+        .fold(("Task", 0), |acc, &(kind, hits)| if hits > acc.1 { (kind, hits) } else { acc });
+
+    if best_hits == 0 {
+        return ("Task", 0.3);
+    }
+
+    let total: usize = candidates.iter().map(|(_, hits)| hits).sum();
+    let dominance = best_hits as f32 / total as f32;
+    (best, confidence_from_hits(best_hits) * dominance.max(0.5))
+}
+
+fn classify_priority(text: &str) -> (&'static str, f32) {
+    let critical = count_hits(text, CRITICAL_KEYWORDS);
+    let high = count_hits(text, HIGH_KEYWORDS);
+    let low = count_hits(text, LOW_KEYWORDS);
+
+    if critical > 0 && critical + high >= 2 {
+        ("Critical", confidence_from_hits(critical + high))
+    } else if critical > 0 || high > 0 {
+        ("High", confidence_from_hits(critical + high))
+    } else if low > 0 {
+        ("Low", confidence_from_hits(low))
+    } else {
+        // 특별한 단서가 없으면 일반적인 내용으로 간주
+        ("Medium", 0.5)
+    }
+}
+
+fn confidence_from_hits(hits: usize) -> f32 {
+    (0.5 + 0.15 * hits as f32).min(0.9)
+}
+
+fn count_hits(text: &str, keywords: &[&str]) -> usize {
+    keywords
+        .iter()
+        .filter(|keyword| contains_keyword(text, keyword))
+        .count()
+}
+
+// 영문 키워드 뒤에 붙을 수 있는 굴절 어미 (crashes, failed, failing, errors)
+const ENGLISH_SUFFIXES: &[&str] = &["", "s", "es", "d", "ed", "ing"];
+
+// 영문 키워드는 앞쪽 단어 경계와 뒤쪽 어미를 확인하고(예: "add"가 "address"에 걸리지 않도록),
+// 한글 키워드는 조사가 붙으므로 부분 일치로 판단합니다.
+fn contains_keyword(text: &str, keyword: &str) -> bool {
+    if !keyword.is_ascii() {
+        return text.contains(keyword);
+    }
+
+    text.match_indices(keyword).any(|(start, _)| {
+        let end = start + keyword.len();
+        let before = text[..start].chars().next_back();
+        let rest = &text[end..];
+        let suffix_len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+        !before.is_some_and(|c| c.is_ascii_alphanumeric()) && ENGLISH_SUFFIXES.contains(&&rest[..suffix_len])
+    })
+}
+
+fn make_title(text: &str) -> String {
+    let first_line = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("제목 없음");
+
+    if first_line.chars().count() <= MAX_TITLE_CHARS {
+        first_line.to_string()
+    } else {
+        let truncated: String = first_line.chars().take(MAX_TITLE_CHARS - 1).collect();
+        format!("{}…", truncated.trim_end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_type_priority_and_labels() {
+        // (입력, 이슈 타입, 우선순위, 라벨)
+        let cases: &[(&str, &str, &str, &[&str])] = &[
+            ("로그인 버튼을 누르면 오류가 발생합니다", "Bug", "Medium", &["ui", "security"]),
+            ("App crashes with an exception on startup", "Bug", "Medium", &[]),
+            ("Please add dark mode support", "Story", "Medium", &[]),
+            ("결제 서비스 장애, 즉시 확인 필요", "Task", "Critical", &[]),
+            ("긴급: API 응답이 느려요", "Task", "High", &["api", "performance"]),
+            ("README 문서 오타 수정", "Task", "Low", &["documentation"]),
+            ("결제 시스템 전면 개편 로드맵", "Epic", "Medium", &[]),
+            // 영문 키워드는 단어 단위로만 인식 ("add"가 "address"에 걸리지 않음)
+            ("Change the billing address field", "Task", "Medium", &[]),
+            // 굴절형도 인식
+            ("Login failed", "Bug", "Medium", &["security"]),
+            ("Save errors out", "Bug", "Medium", &[]),
+            ("Checkout crashes", "Bug", "Medium", &[]),
+            ("Checkout crashed twice", "Bug", "Medium", &[]),
+            // 숫자 500만으로는 버그가 아님
+            ("Prepare onboarding for 500 users", "Task", "Medium", &[]),
+            ("Server returns HTTP 500", "Bug", "Medium", &["api"]),
+        ];
+
+        for (text, issue_type, priority, labels) in cases {
+            let analysis = classify(text);
+            let mut expected: Vec<String> = labels.iter().map(|l| l.to_string()).collect();
+            expected.push("offline-analysis".to_string());
+            assert_eq!(analysis.issue_type, *issue_type, "입력: {}", text);
+            assert_eq!(analysis.priority, *priority, "입력: {}", text);
+            assert_eq!(analysis.labels, expected, "입력: {}", text);
+        }
+    }
+
+    #[test]
+    fn confidence_reflects_keyword_hits() {
+        // 단서가 없으면 Task 0.3과 Medium 0.5의 평균
+        assert_eq!(classify("내일 회의").confidence, Some(0.4));
+        let strong = classify("버그: 로그인 실패 오류, 앱이 튕김").confidence.unwrap();
+        let weak = classify("로그인 오류").confidence.unwrap();
+        assert!(strong > weak, "{} > {}", strong, weak);
+        assert!(strong <= 0.9);
+    }
+
+    #[test]
+    fn builds_title_from_first_line() {
+        let cases = [
+            ("\n  첫 줄 제목  \n둘째 줄", "첫 줄 제목".to_string()),
+            ("", "제목 없음".to_string()),
+            (&*"가".repeat(60), format!("{}…", "가".repeat(MAX_TITLE_CHARS - 1))),
+        ];
+        for (text, title) in cases {
+            assert_eq!(classify(text).title, title);
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::jira_api::{self, JiraAuth};
+use crate::similarity;
+use crate::{IssueAnalysis, JiraConfig};
+
+// 이슈 생성 전 프로젝트의 열린 이슈 중 중복 후보를 찾습니다.
+// 1) 제목/설명에서 핵심 단어를 뽑아 JQL 텍스트 검색으로 후보를 모으고
+// 2) 문자 바이그램 유사도로 점수를 매겨 정렬합니다.
+const MAX_KEY_TERMS: usize = 8;
+const SEARCH_RESULTS: usize = 50;
+const MIN_SCORE: f32 = 0.15;
+
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "when", "into", "not", "are", "was", "but", "have",
+    "should", "can", "will", "issue", "please",
+    "그리고", "하지만", "그래서", "에서", "으로", "합니다", "있습니다", "없습니다", "됩니다", "해주세요", "있음",
+    "없음", "이슈", "관련", "경우", "문제", "부탁드립니다",
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DuplicateCandidate {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub issue_type: String,
+    pub score: f32,
+}
+
+// 제목에 나온 단어는 설명보다 가중치를 높게 줍니다.
+fn extract_key_terms(analysis: &IssueAnalysis) -> Vec<String> {
+    let mut weights: HashMap<String, (usize, usize)> = HashMap::new();
+    let sources = [(&analysis.title, 3), (&analysis.description, 1)];
+
+    let mut order = 0;
+    for (text, weight) in sources {
+        for word in text.split(|c: char| !c.is_alphanumeric()) {
+            let word = word.to_lowercase();
+            if word.chars().count() < 2 || word.chars().all(|c| c.is_ascii_digit()) || STOPWORDS.contains(&word.as_str()) {
+                continue;
+            }
+            let entry = weights.entry(word).or_insert((0, order));
+            entry.0 += weight;
+            order += 1;
+        }
+    }
+
+    let mut terms: Vec<(String, (usize, usize))> = weights.into_iter().collect();
+    // 가중치 내림차순, 같으면 먼저 등장한 단어 우선
+    terms.sort_by(|a, b| b.1 .0.cmp(&a.1 .0).then(a.1 .1.cmp(&b.1 .1)));
+    terms.into_iter().take(MAX_KEY_TERMS).map(|(term, _)| term).collect()
+}
+
+fn escape_jql_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// text ~ 검색어는 Lucene 문법으로 해석되므로 예약 문자를 \\로 한 번 더 이스케이프
+fn escape_jql_text(value: &str) -> String {
+    const RESERVED: &str = "+-&|!(){}[]^~*?:/";
+    let mut escaped = String::new();
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\\\\\"),
+            '"' => escaped.push_str("\\\""),
+            c if RESERVED.contains(c) => {
+                escaped.push_str("\\\\");
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn build_duplicate_jql(project_key: &str, terms: &[String]) -> String {
+    let clauses: Vec<String> = terms
+        .iter()
+        .map(|term| format!("text ~ \"{}\"", escape_jql_text(term)))
+        .collect();
+
+    format!(
+        "project = \"{}\" AND statusCategory != Done AND ({}) ORDER BY updated DESC",
+        escape_jql_string(project_key),
+        clauses.join(" OR ")
+    )
+}
+
+fn score_candidate(analysis: &IssueAnalysis, issue: &serde_json::Value) -> Option<DuplicateCandidate> {
+    let fields = &issue["fields"];
+    let key = issue["key"].as_str()?.to_string();
+    let summary = fields["summary"].as_str().unwrap_or("").to_string();
+
+    let description = jira_api::rich_text_to_plain_text(&fields["description"]);
+
+    let summary_score = similarity::text_similarity(&analysis.title, &summary);
+    let description_score = if description.trim().is_empty() || analysis.description.trim().is_empty() {
+        summary_score
+    } else {
+        similarity::text_similarity(&analysis.description, &description)
+    };
+
+    Some(DuplicateCandidate {
+        key,
+        summary,
+        status: fields["status"]["name"].as_str().unwrap_or("").to_string(),
+        issue_type: fields["issuetype"]["name"].as_str().unwrap_or("").to_string(),
+        score: summary_score * 0.7 + description_score * 0.3,
+    })
+}
+
+pub async fn find_duplicates(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    analysis: &IssueAnalysis,
+    limit: usize,
+) -> Result<Vec<DuplicateCandidate>, String> {
+    let terms = extract_key_terms(analysis);
+    if terms.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let jql = build_duplicate_jql(&config.project_key, &terms);
+    println!("중복 검색 JQL: {}", jql);
+
+    let url = jira_api::api_url(config, jira_api::search_path(config));
+    let response = client
+        .post(&url)
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .json(&serde_json::json!({
+            "jql": jql,
+            "fields": ["summary", "description", "status", "issuetype"],
+            "maxResults": SEARCH_RESULTS
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(format!("Jira 중복 검색 오류 ({}): {}", status, error_text));
+    }
+
+    let response_data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+    let mut candidates: Vec<DuplicateCandidate> = response_data["issues"]
+        .as_array()
+        .map(|issues| issues.iter().filter_map(|issue| score_candidate(analysis, issue)).collect())
+        .unwrap_or_default();
+
+    candidates.retain(|c| c.score >= MIN_SCORE);
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates.truncate(limit);
+
+    Ok(candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn analysis(title: &str, description: &str) -> IssueAnalysis {
+        IssueAnalysis {
+            title: title.to_string(),
+            description: description.to_string(),
+            issue_type: "Bug".to_string(),
+            priority: "Medium".to_string(),
+            labels: vec![],
+            confidence: None,
+            sections: None,
+        }
+    }
+
+    fn issue(summary: &str, description: &str) -> serde_json::Value {
+        json!({
+            "key": "TEST-1",
+            "fields": {
+                "summary": summary,
+                "description": description,
+                "status": { "name": "To Do" },
+                "issuetype": { "name": "Bug" }
+            }
+        })
+    }
+
+    #[test]
+    fn extracts_key_terms_by_weight() {
+        let cases: &[(&str, &str, &[&str])] = &[
+            // 제목 단어가 설명 단어보다 앞서고, 불용어/한 글자/숫자는 제외
+            ("Login page crash", "The login button is broken on page 2", &["login", "page", "crash", "button", "is", "broken", "on"]),
+            ("로그인 오류", "로그인 시 오류 발생 문제", &["로그인", "오류", "발생"]),
+            // 따옴표와 역슬래시는 단어 구분자로 취급
+            ("Path \"C:\\temp\" not found", "", &["path", "temp", "found"]),
+            ("the and 123", "", &[]),
+        ];
+        for (title, description, expected) in cases {
+            assert_eq!(extract_key_terms(&analysis(title, description)), *expected, "입력: {}", title);
+        }
+
+        let long = analysis("alpha beta gamma delta epsilon zeta theta iota kappa lambda", "");
+        assert_eq!(extract_key_terms(&long).len(), MAX_KEY_TERMS);
+    }
+
+    #[test]
+    fn escapes_quotes_and_reserved_characters_in_jql() {
+        let cases = [
+            ("login", r#"text ~ "login""#),
+            (r#"say "hi""#, r#"text ~ "say \"hi\"""#),
+            (r"C:\temp", r#"text ~ "C\\:\\\\temp""#),
+            ("a+b-c", r#"text ~ "a\\+b\\-c""#),
+            ("(x)?*", r#"text ~ "\\(x\\)\\?\\*""#),
+        ];
+        for (term, clause) in cases {
+            let jql = build_duplicate_jql("TEST", &[term.to_string()]);
+            assert!(jql.contains(clause), "입력: {} → {}", term, jql);
+        }
+
+        let jql = build_duplicate_jql(r#"MY"KEY"#, &["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            jql,
+            r#"project = "MY\"KEY" AND statusCategory != Done AND (text ~ "a" OR text ~ "b") ORDER BY updated DESC"#
+        );
+
+        // 추출된 단어로 만든 JQL에는 이스케이프되지 않은 따옴표가 남지 않음
+        let terms = extract_key_terms(&analysis("Path \"C:\\temp\" not found", "quote \" and \\ backslash"));
+        let jql = build_duplicate_jql("TEST", &terms);
+        assert!(!jql.contains(r#"\""#) && !jql.contains(r"\\"), "{}", jql);
+    }
+
+    #[test]
+    fn weights_summary_and_description_against_min_score() {
+        let base = analysis("결제 페이지 오류", "카드 결제 시 500 오류가 발생합니다");
+
+        // 설명이 없으면 제목 점수만 사용
+        let same = score_candidate(&base, &issue("결제 페이지 오류", "")).unwrap();
+        assert!((same.score - 1.0).abs() < 1e-6);
+
+        // 제목 0.7 + 설명 0.3
+        let title_only = score_candidate(&base, &issue("결제 페이지 오류", "전혀 다른 내용")).unwrap();
+        assert!((title_only.score - 0.7).abs() < 1e-6, "{}", title_only.score);
+        let description_only = score_candidate(&base, &issue("무관한 요약", "카드 결제 시 500 오류가 발생합니다")).unwrap();
+        assert!((description_only.score - 0.3).abs() < 1e-6, "{}", description_only.score);
+        assert!(description_only.score >= MIN_SCORE);
+
+        let unrelated = score_candidate(&base, &issue("회원 탈퇴 기능", "계정 삭제 요청")).unwrap();
+        assert!(unrelated.score < MIN_SCORE, "{}", unrelated.score);
+        assert_eq!(unrelated.status, "To Do");
+        assert_eq!(unrelated.issue_type, "Bug");
+    }
+}
@@ -0,0 +1,327 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::redact::{RedactionConfig, Redactor};
+use crate::similarity;
+use crate::IssueAnalysis;
+
+// 사용자가 실제로 등록한 (입력 → 최종 이슈) 쌍을 저장해 두고
+// 새 요청과 비슷한 예시를 프롬프트에 포함하여 팀의 분류/라벨 규칙을 따르게 합니다.
+const EXAMPLES_DIR: &str = "examples";
+const EXAMPLES_FILE: &str = "accepted_issues.jsonl";
+const MAX_STORED_EXAMPLES: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FewShotConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_max_examples")]
+    pub max_examples: usize,
+    // 예시 부분이 프롬프트에서 차지할 수 있는 최대 글자 수
+    #[serde(default = "default_max_prompt_chars")]
+    pub max_prompt_chars: usize,
+    #[serde(default = "default_min_similarity")]
+    pub min_similarity: f32,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_max_examples() -> usize {
+    3
+}
+
+fn default_max_prompt_chars() -> usize {
+    4000
+}
+
+fn default_min_similarity() -> f32 {
+    0.1
+}
+
+impl Default for FewShotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            max_examples: default_max_examples(),
+            max_prompt_chars: default_max_prompt_chars(),
+            min_similarity: default_min_similarity(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcceptedExample {
+    pub text: String,
+    pub analysis: IssueAnalysis,
+    pub accepted_at: DateTime<Utc>,
+}
+
+fn examples_path(dir: &Path) -> PathBuf {
+    dir.join(EXAMPLES_FILE)
+}
+
+fn load_all(dir: &Path) -> Result<Vec<AcceptedExample>, String> {
+    let path = examples_path(dir);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let file = fs::File::open(&path).map_err(|e| format!("예시 파일 열기 실패: {}", e))?;
+    let mut examples = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| format!("예시 파일 읽기 실패: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<AcceptedExample>(&line) {
+            Ok(example) => examples.push(example),
+            Err(e) => println!("잘못된 예시 항목을 건너뜁니다: {}", e),
+        }
+    }
+    Ok(examples)
+}
+
+// 입력과 결과에 있는 민감 정보는 AI 설정의 마스킹 규칙으로 저장 전에 마스킹합니다.
+// (복원하지 않으므로 원문은 디스크에 남지 않음)
+pub fn record(text: &str, analysis: IssueAnalysis, redaction: &RedactionConfig) -> Result<(), String> {
+    record_in(Path::new(EXAMPLES_DIR), text, analysis, redaction)
+}
+
+fn record_in(dir: &Path, text: &str, analysis: IssueAnalysis, redaction: &RedactionConfig) -> Result<(), String> {
+    let redactor = Redactor::new(redaction)?;
+    let example = AcceptedExample {
+        text: redactor.mask(text.trim()),
+        analysis: IssueAnalysis {
+            title: redactor.mask(&analysis.title),
+            description: redactor.mask(&analysis.description),
+            labels: analysis.labels.iter().map(|l| redactor.mask(l)).collect(),
+            sections: analysis.sections.as_ref().map(|s| s.map_text(|t| redactor.mask(t))),
+            confidence: None,
+            ..analysis
+        },
+        accepted_at: Utc::now(),
+    };
+
+    fs::create_dir_all(dir).map_err(|e| format!("예시 디렉토리 생성 실패: {}", e))?;
+
+    // 같은 입력은 최신 결과로 교체하고, 저장 개수가 넘으면 오래된 것부터 버립니다.
+    let mut examples = load_all(dir)?;
+    examples.retain(|e| e.text != example.text);
+    examples.push(example);
+    if examples.len() > MAX_STORED_EXAMPLES {
+        let excess = examples.len() - MAX_STORED_EXAMPLES;
+        examples.drain(..excess);
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(examples_path(dir))
+        .map_err(|e| format!("예시 파일 열기 실패: {}", e))?;
+    for example in &examples {
+        let line = serde_json::to_string(example).map_err(|e| format!("예시 직렬화 실패: {}", e))?;
+        writeln!(file, "{}", line).map_err(|e| format!("예시 저장 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+// 입력과 가장 비슷한 예시를 유사도 순으로 선택
+pub fn select(config: &FewShotConfig, text: &str) -> Vec<AcceptedExample> {
+    select_from(Path::new(EXAMPLES_DIR), config, text)
+}
+
+fn select_from(dir: &Path, config: &FewShotConfig, text: &str) -> Vec<AcceptedExample> {
+    if !config.enabled || config.max_examples == 0 {
+        return vec![];
+    }
+
+    let examples = match load_all(dir) {
+        Ok(examples) => examples,
+        Err(e) => {
+            println!("{}", e);
+            return vec![];
+        }
+    };
+
+    let target = similarity::bigrams(text);
+    let mut scored: Vec<(f32, AcceptedExample)> = examples
+        .into_iter()
+        .map(|example| (similarity::jaccard(&target, &similarity::bigrams(&example.text)), example))
+        .filter(|(score, _)| *score >= config.min_similarity)
+        .collect();
+
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| b.1.accepted_at.cmp(&a.1.accepted_at)));
+    scored
+        .into_iter()
+        .take(config.max_examples)
+        .map(|(_, example)| example)
+        .collect()
+}
+
+// 프롬프트에 넣을 예시 섹션 작성
+// 저장 후 마스킹 규칙이 바뀌었을 수 있으므로 현재 요청의 규칙으로 다시 마스킹합니다.
+pub fn render(config: &FewShotConfig, examples: &[AcceptedExample], redactor: &Redactor) -> String {
+    let mut section = String::new();
+    let mut count = 0;
+
+    for example in examples {
+        let result = serde_json::json!({
+            "title": redactor.mask(&example.analysis.title),
+            "description": redactor.mask(&example.analysis.description),
+            "issue_type": example.analysis.issue_type,
+            "priority": example.analysis.priority,
+            "labels": example.analysis.labels.iter().map(|l| redactor.mask(l)).collect::<Vec<_>>(),
+        });
+        let entry = format!(
+            "\n예시 {})\n사용자 요청: {}\n최종 이슈: {}\n",
+            count + 1,
+            redactor.mask(&example.text),
+            result
+        );
+
+        if section.chars().count() + entry.chars().count() > config.max_prompt_chars {
+            break;
+        }
+        section.push_str(&entry);
+        count += 1;
+    }
+
+    if count == 0 {
+        return String::new();
+    }
+
+    format!(
+        "\n\n다음은 이 팀이 실제로 등록한 이슈 예시입니다. 제목 형식, 이슈 타입, 우선순위, 라벨 규칙을 예시와 동일하게 따라주세요.\n{}",
+        section
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redaction(custom_patterns: &[&str]) -> RedactionConfig {
+        RedactionConfig {
+            enabled: true,
+            custom_patterns: custom_patterns.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn analysis(title: &str, labels: &[&str]) -> IssueAnalysis {
+        IssueAnalysis {
+            title: title.to_string(),
+            description: format!("{} 설명", title),
+            issue_type: "Bug".to_string(),
+            priority: "High".to_string(),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            confidence: Some(0.8),
+            sections: None,
+        }
+    }
+
+    fn example(text: &str, title: &str) -> AcceptedExample {
+        AcceptedExample {
+            text: text.to_string(),
+            analysis: analysis(title, &[]),
+            accepted_at: Utc::now(),
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pengu_few_shot_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn record_masks_with_configured_patterns() {
+        let dir = temp_dir("record");
+        let config = redaction(&[r"CUST-\d+"]);
+        record_in(&dir, "CUST-1234 고객 admin@corp.io 로그인 실패", analysis("CUST-1234 로그인 실패", &["CUST-1234"]), &config)
+            .unwrap();
+        // 같은 입력은 최신 결과로 교체
+        record_in(&dir, "CUST-1234 고객 admin@corp.io 로그인 실패", analysis("CUST-5678 로그인 오류", &["login"]), &config)
+            .unwrap();
+
+        let stored = load_all(&dir).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].text, "[REDACTED_CUSTOM] 고객 [REDACTED_EMAIL] 로그인 실패");
+        assert_eq!(stored[0].analysis.title, "[REDACTED_CUSTOM] 로그인 오류");
+        assert_eq!(stored[0].analysis.labels, ["login"]);
+        assert_eq!(stored[0].analysis.confidence, None);
+
+        let content = fs::read_to_string(examples_path(&dir)).unwrap();
+        assert!(!content.contains("CUST-") && !content.contains("admin@corp.io"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn selects_most_similar_examples() {
+        let dir = temp_dir("select");
+        let config = redaction(&[]);
+        for (text, title) in [
+            ("로그인 버튼을 누르면 앱이 멈춤", "로그인 멈춤"),
+            ("결제 화면에서 카드 등록 실패", "카드 등록 실패"),
+            ("로그인 화면에서 비밀번호 입력 시 앱이 멈춤", "비밀번호 입력 멈춤"),
+        ] {
+            record_in(&dir, text, analysis(title, &[]), &config).unwrap();
+        }
+
+        let few_shot = FewShotConfig {
+            max_examples: 2,
+            ..Default::default()
+        };
+        let selected = select_from(&dir, &few_shot, "로그인 버튼 누르면 앱 멈춤");
+        let titles: Vec<&str> = selected.iter().map(|e| e.analysis.title.as_str()).collect();
+        assert_eq!(titles, ["로그인 멈춤", "비밀번호 입력 멈춤"]);
+
+        let disabled = FewShotConfig {
+            enabled: false,
+            ..Default::default()
+        };
+        assert!(select_from(&dir, &disabled, "로그인 버튼 누르면 앱 멈춤").is_empty());
+        assert!(select_from(&dir, &FewShotConfig::default(), "전혀 관계없는 문장").is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn render_respects_prompt_char_limit() {
+        let redactor = Redactor::new(&redaction(&[])).unwrap();
+        let examples = [example("첫 번째 요청", "첫 번째"), example(&"가".repeat(300), "두 번째")];
+
+        let section = render(&FewShotConfig::default(), &examples, &redactor);
+        assert!(section.contains("예시 1)") && section.contains("예시 2)"));
+
+        let limited = FewShotConfig {
+            max_prompt_chars: 200,
+            ..Default::default()
+        };
+        let section = render(&limited, &examples, &redactor);
+        assert!(section.contains("사용자 요청: 첫 번째 요청"));
+        assert!(!section.contains("예시 2)"));
+
+        let tiny = FewShotConfig {
+            max_prompt_chars: 10,
+            ..Default::default()
+        };
+        assert_eq!(render(&tiny, &examples, &redactor), "");
+    }
+
+    // 저장 후 추가된 사용자 정의 패턴도 프롬프트에 넣기 전에 적용
+    #[test]
+    fn render_masks_with_current_redactor() {
+        let redactor = Redactor::new(&redaction(&[r"intra\.corp\.local"])).unwrap();
+        let examples = [example("intra.corp.local 접속 불가, 담당 ops@corp.io", "intra.corp.local 장애")];
+
+        let section = render(&FewShotConfig::default(), &examples, &redactor);
+        assert!(section.contains("[REDACTED_CUSTOM] 접속 불가, 담당 [REDACTED_EMAIL]"));
+        assert!(section.contains("[REDACTED_CUSTOM] 장애"));
+        assert!(!section.contains("intra.corp.local") && !section.contains("ops@corp.io"));
+    }
+}
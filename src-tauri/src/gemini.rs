@@ -0,0 +1,105 @@
+use crate::ai_usage;
+use crate::AIConfig;
+
+// Gemini generateContent 호출 공통 처리
+pub const GEMINI_API_BASE: &str = "https://generativelanguage.googleapis.com/v1beta/models";
+
+pub enum GeminiError {
+    // 네트워크 오류나 서버 측 장애 (로컬 분류기로 대체 가능)
+    Unreachable(String),
+    Failed(String),
+}
+
+impl From<GeminiError> for String {
+    fn from(error: GeminiError) -> Self {
+        match error {
+            GeminiError::Unreachable(message) | GeminiError::Failed(message) => message,
+        }
+    }
+}
+
+pub fn error_message(status: reqwest::StatusCode, error_text: &str, model: &str) -> String {
+    // 404 오류인 경우 모델 관련 안내 메시지 추가
+    if status == 404 {
+        format!("Gemini API 모델 오류: '{}' 모델을 찾을 수 없습니다. 지원되는 모델: gemini-2.0-flash-exp, gemini-2.5-pro, gemini-2.5-flash, gemini-2.5-flash-lite, gemini-live-2.5-flash-preview, gemini-2.0-flash-live-001", model)
+    } else {
+        format!("Gemini API 오류 ({}): {}", status, error_text)
+    }
+}
+
+// 요청을 보내고 첫 번째 후보의 텍스트를 반환합니다. 토큰 사용량도 함께 기록합니다.
+pub async fn generate_content(
+    client: &reqwest::Client,
+    config: &AIConfig,
+    request_data: &serde_json::Value,
+) -> Result<String, GeminiError> {
+    let url = format!("{}/{}:generateContent", GEMINI_API_BASE, config.model);
+
+    let response = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .query(&[("key", &config.api_key)])
+        .json(request_data)
+        .send()
+        .await
+        .map_err(|e| GeminiError::Unreachable(format!("Gemini API 네트워크 오류: {}", e)))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+
+        println!("Gemini API 오류 ({}): {}", status, error_text);
+
+        let message = error_message(status, &error_text, &config.model);
+        // 서버 측 장애는 연결 불가와 동일하게 취급
+        return Err(if status.is_server_error() {
+            GeminiError::Unreachable(message)
+        } else {
+            GeminiError::Failed(message)
+        });
+    }
+
+    let response_data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| GeminiError::Failed(format!("Gemini API 응답 파싱 오류: {}", e)))?;
+
+    println!("Gemini API 응답: {:?}", response_data);
+
+    if let Err(e) = ai_usage::record(&config.model, &response_data["usageMetadata"], &config.pricing) {
+        println!("{}", e);
+    }
+
+    // 응답 구조 분석
+    let candidate = response_data["candidates"]
+        .as_array()
+        .and_then(|candidates| candidates.first())
+        .ok_or_else(|| GeminiError::Failed("응답 candidates가 없습니다.".to_string()))?;
+
+    let finish_reason = candidate["finishReason"].as_str().unwrap_or("");
+    println!("Finish reason: {}", finish_reason);
+
+    // finishReason이 MAX_TOKENS인 경우 처리
+    if finish_reason == "MAX_TOKENS" {
+        return Err(GeminiError::Failed(
+            "응답이 너무 길어서 잘렸습니다. 더 간단한 요청을 시도해주세요.".to_string(),
+        ));
+    }
+
+    // content에서 텍스트 추출
+    let parts = candidate["content"]
+        .as_object()
+        .ok_or_else(|| GeminiError::Failed("응답 content가 없습니다.".to_string()))?
+        .get("parts")
+        .and_then(|parts| parts.as_array())
+        .ok_or_else(|| GeminiError::Failed("응답 content 구조가 올바르지 않습니다.".to_string()))?;
+
+    if parts.is_empty() {
+        return Err(GeminiError::Failed("응답 parts가 비어있습니다.".to_string()));
+    }
+
+    parts[0]["text"]
+        .as_str()
+        .map(|text| text.to_string())
+        .ok_or_else(|| GeminiError::Failed("응답에서 텍스트를 찾을 수 없습니다.".to_string()))
+}
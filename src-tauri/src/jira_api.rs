@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::adf_render;
+use crate::markdown_adf;
+use crate::markdown_wiki;
+use crate::JiraConfig;
+
+// Jira 배포 형태(Cloud / Server·Data Center)별 인증 방식과 REST API 버전 처리
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JiraAuthMode {
+    // Jira Cloud: 이메일 + API 토큰
+    #[default]
+    CloudBasic,
+    // Server/Data Center: 개인 액세스 토큰 (Bearer, api_token 사용)
+    ServerPat,
+    // Server/Data Center: 사용자 이름(email 칸) + 비밀번호(api_token 칸)
+    ServerBasic,
+    // Jira Cloud: OAuth 2.0 (3LO) 로그인 토큰
+    CloudOauth,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum JiraApiVersion {
+    // 설명/댓글을 ADF로 주고받음
+    #[default]
+    #[serde(rename = "v3", alias = "3")]
+    V3,
+    // 설명/댓글을 위키 마크업 문자열로 주고받음
+    #[serde(rename = "v2", alias = "2")]
+    V2,
+}
+
+pub fn is_server(config: &JiraConfig) -> bool {
+    matches!(config.auth_mode, JiraAuthMode::ServerPat | JiraAuthMode::ServerBasic)
+}
+
+// Server/Data Center에는 v3 API가 없으므로 항상 v2를 사용
+pub fn api_version(config: &JiraConfig) -> JiraApiVersion {
+    if is_server(config) {
+        JiraApiVersion::V2
+    } else {
+        config.api_version
+    }
+}
+
+pub fn api_url(config: &JiraConfig, path: &str) -> String {
+    let version = match api_version(config) {
+        JiraApiVersion::V3 => 3,
+        JiraApiVersion::V2 => 2,
+    };
+    // OAuth 로그인 시에는 api.atlassian.com/ex/jira/{cloudid} 경유
+    let base_url = match &config.oauth_session {
+        Some(session) => &session.api_base_url,
+        None => &config.base_url,
+    };
+    format!("{}/rest/api/{}/{}", base_url.trim_end_matches('/'), version, path)
+}
+
+// JQL 검색 경로 (Cloud는 토큰 기반 새 검색 API, Server는 startAt 기반 검색 API)
+pub fn search_path(config: &JiraConfig) -> &'static str {
+    if is_server(config) {
+        "search"
+    } else {
+        "search/jql"
+    }
+}
+
+pub trait JiraAuth: Sized {
+    fn jira_auth(self, config: &JiraConfig) -> Result<Self, String>;
+}
+
+impl JiraAuth for reqwest::RequestBuilder {
+    fn jira_auth(self, config: &JiraConfig) -> Result<Self, String> {
+        match config.auth_mode {
+            JiraAuthMode::CloudBasic | JiraAuthMode::ServerBasic => Ok(self.basic_auth(&config.email, Some(&config.api_token))),
+            JiraAuthMode::ServerPat => Ok(self.bearer_auth(&config.api_token)),
+            // jira_oauth::authorize를 거치지 않은 설정으로 인증 없이 요청하지 않도록 오류 처리
+            JiraAuthMode::CloudOauth => match &config.oauth_session {
+                Some(session) => Ok(self.bearer_auth(&session.access_token)),
+                None => Err("Jira OAuth 로그인이 필요합니다.".to_string()),
+            },
+        }
+    }
+}
+
+// Markdown을 설명/댓글 필드 값으로 변환 (v3: ADF 문서, v2: 위키 마크업)
+pub fn rich_text(config: &JiraConfig, markdown: &str) -> serde_json::Value {
+    match api_version(config) {
+        JiraApiVersion::V3 => markdown_adf::markdown_to_adf(markdown),
+        JiraApiVersion::V2 => serde_json::Value::String(markdown_wiki::markdown_to_wiki(markdown)),
+    }
+}
+
+// 설명/댓글 필드 값을 Markdown으로 (v3는 ADF 객체, v2는 위키 마크업 문자열)
+pub fn rich_text_to_markdown(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(wiki) => markdown_wiki::wiki_to_markdown(wiki),
+        _ => adf_render::adf_to_markdown(value),
+    }
+}
+
+// 설명/댓글 필드 값의 평문 (검색, 미리보기용)
+pub fn rich_text_to_plain_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(wiki) => {
+            adf_render::adf_to_plain_text(&markdown_adf::markdown_to_adf(&markdown_wiki::wiki_to_markdown(wiki)))
+        }
+        _ => adf_render::adf_to_plain_text(value),
+    }
+}
+
+// 사용자 필드 값 (Cloud: accountId, Server: 사용자 이름), None이면 해제
+pub fn user_ref(config: &JiraConfig, user_id: Option<&str>) -> serde_json::Value {
+    if is_server(config) {
+        serde_json::json!({ "name": user_id })
+    } else {
+        serde_json::json!({ "accountId": user_id })
+    }
+}
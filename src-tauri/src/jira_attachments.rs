@@ -0,0 +1,281 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+
+use crate::attachments;
+use crate::http_client;
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_fields;
+use crate::JiraConfig;
+
+// 이슈에 파일 첨부 (로그, 스크린샷)
+// 서버 업로드 한도를 조회하지 못하면 Jira 기본값(10MB)을 기준으로 검사합니다.
+const DEFAULT_UPLOAD_LIMIT: u64 = 10 * 1024 * 1024;
+const MAX_FILES: usize = 20;
+
+// 파일별 업로드 결과 (일부 실패해도 나머지는 계속 업로드)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttachmentUploadResult {
+    pub path: String,
+    pub file_name: String,
+    pub size: u64,
+    pub success: bool,
+    pub attachment_id: Option<String>,
+    pub error: Option<String>,
+}
+
+// 첨부 기능 활성화 여부와 업로드 한도 조회
+async fn fetch_upload_limit(client: &reqwest::Client, config: &JiraConfig) -> Result<u64, String> {
+    let response = client
+        .get(jira_api::api_url(config, "attachment/meta"))
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        println!("첨부 파일 설정 조회 실패 ({}), 기본 한도를 사용합니다.", response.status());
+        return Ok(DEFAULT_UPLOAD_LIMIT);
+    }
+
+    let meta: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+    if meta["enabled"].as_bool() == Some(false) {
+        return Err("이 Jira 사이트에서는 파일 첨부가 비활성화되어 있습니다.".to_string());
+    }
+    Ok(meta["uploadLimit"].as_u64().unwrap_or(DEFAULT_UPLOAD_LIMIT))
+}
+
+fn mime_type(path: &Path, bytes: &[u8]) -> &'static str {
+    if let Some(mime) = attachments::detect_binary_mime(bytes) {
+        return mime;
+    }
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    if attachments::TEXT_EXTENSIONS.contains(&extension.as_str()) {
+        "text/plain"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+fn check_upload_size(size: u64, upload_limit: u64) -> Result<(), String> {
+    if size > upload_limit {
+        return Err(format!(
+            "파일이 너무 큽니다 ({:.1}MB, 최대 {:.1}MB)",
+            size as f64 / 1024.0 / 1024.0,
+            upload_limit as f64 / 1024.0 / 1024.0
+        ));
+    }
+    Ok(())
+}
+
+// 한도를 확인하고 형식 판별에 필요한 앞부분만 읽은 뒤 처음 위치로 되돌린 파일을 반환
+async fn open_attachment(path: &Path, upload_limit: u64) -> Result<(tokio::fs::File, u64, &'static str), String> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| format!("파일 열기 실패: {}", e))?;
+    let size = file
+        .metadata()
+        .await
+        .map_err(|e| format!("파일 정보 조회 실패: {}", e))?
+        .len();
+    check_upload_size(size, upload_limit)?;
+
+    let mut head = Vec::new();
+    AsyncReadExt::take(&mut file, 16)
+        .read_to_end(&mut head)
+        .await
+        .map_err(|e| format!("파일 읽기 실패: {}", e))?;
+    file.rewind().await.map_err(|e| format!("파일 읽기 실패: {}", e))?;
+
+    Ok((file, size, mime_type(path, &head)))
+}
+
+async fn upload_file(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    path: &Path,
+    file_name: &str,
+    upload_limit: u64,
+) -> Result<(u64, String), String> {
+    // 큰 파일을 메모리에 올리지 않도록 스트림으로 전송
+    let (file, size, mime) = open_attachment(path, upload_limit).await?;
+    let body = reqwest::Body::wrap_stream(ReaderStream::new(file));
+    let part = reqwest::multipart::Part::stream_with_length(body, size)
+        .file_name(file_name.to_string())
+        .mime_str(mime)
+        .map_err(|e| format!("첨부 파일 형식 오류: {}", e))?;
+    let form = reqwest::multipart::Form::new().part("file", part);
+
+    let response = client
+        .post(jira_api::api_url(config, &format!("issue/{}/attachments", issue_key)))
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        // XSRF 검사를 우회하기 위해 Jira가 요구하는 헤더
+        .header("X-Atlassian-Token", "no-check")
+        .timeout(http_client::UPLOAD_TIMEOUT)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    // 응답은 업로드된 첨부 파일 목록
+    let uploaded: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+    let attachment_id = uploaded[0]["id"].as_str().unwrap_or("").to_string();
+
+    Ok((size, attachment_id))
+}
+
+pub async fn attach_files(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    paths: &[String],
+) -> Result<Vec<AttachmentUploadResult>, String> {
+    if paths.is_empty() {
+        return Ok(vec![]);
+    }
+    if paths.len() > MAX_FILES {
+        return Err(format!("한 번에 최대 {}개까지 첨부할 수 있습니다.", MAX_FILES));
+    }
+
+    let upload_limit = fetch_upload_limit(client, config).await?;
+    let mut results = Vec::new();
+
+    for path_str in paths {
+        let path = Path::new(path_str);
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path_str.clone());
+
+        let outcome = upload_file(client, config, issue_key, path, &file_name, upload_limit).await;
+        match &outcome {
+            Ok(_) => println!("첨부 완료: {} → {}", file_name, issue_key),
+            Err(e) => println!("첨부 실패: {} ({})", file_name, e),
+        }
+        results.push(upload_result(path_str, file_name, outcome));
+    }
+
+    Ok(results)
+}
+
+fn upload_result(path_str: &str, file_name: String, outcome: Result<(u64, String), String>) -> AttachmentUploadResult {
+    match outcome {
+        Ok((size, attachment_id)) => AttachmentUploadResult {
+            path: path_str.to_string(),
+            file_name,
+            size,
+            success: true,
+            attachment_id: Some(attachment_id),
+            error: None,
+        },
+        Err(e) => AttachmentUploadResult {
+            path: path_str.to_string(),
+            size: fs::metadata(path_str).map(|m| m.len()).unwrap_or(0),
+            file_name,
+            success: false,
+            attachment_id: None,
+            error: Some(e),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("pengu_attach_{}_{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn checks_upload_size_limit() {
+        let cases = [
+            (0, 10, true),
+            (10, 10, true),
+            (11, 10, false),
+            (DEFAULT_UPLOAD_LIMIT + 1, DEFAULT_UPLOAD_LIMIT, false),
+        ];
+        for (size, limit, ok) in cases {
+            assert_eq!(check_upload_size(size, limit).is_ok(), ok, "입력: {} / {}", size, limit);
+        }
+        let error = check_upload_size(15 * 1024 * 1024, DEFAULT_UPLOAD_LIMIT).unwrap_err();
+        assert!(error.contains("15.0MB") && error.contains("10.0MB"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn opens_attachment_from_start_with_detected_type() {
+        let png = [&[0x89, b'P', b'N', b'G'][..], &[0u8; 40]].concat();
+        let cases: [(&str, &[u8], &str); 3] = [
+            ("shot.png", &png, "image/png"),
+            ("app.log", b"line 1\nline 2\n", "text/plain"),
+            ("data.bin", b"\x00\x01", "application/octet-stream"),
+        ];
+        for (name, content, mime) in cases {
+            let path = temp_file(name, content);
+            let (mut file, size, detected) = open_attachment(&path, DEFAULT_UPLOAD_LIMIT).await.unwrap();
+            // 형식 판별 후에도 처음부터 전체 내용을 전송
+            let mut sent = Vec::new();
+            file.read_to_end(&mut sent).await.unwrap();
+            fs::remove_file(&path).ok();
+
+            assert_eq!(detected, mime, "입력: {}", name);
+            assert_eq!(size, content.len() as u64);
+            assert_eq!(sent, content);
+        }
+
+        let path = temp_file("big.log", &[b'a'; 32]);
+        let error = open_attachment(&path, 16).await.unwrap_err();
+        fs::remove_file(&path).ok();
+        assert!(error.contains("너무 큽니다"));
+
+        let missing = std::env::temp_dir().join("pengu_attach_missing.log");
+        assert!(open_attachment(&missing, DEFAULT_UPLOAD_LIMIT).await.unwrap_err().contains("파일 열기 실패"));
+    }
+
+    #[test]
+    fn builds_per_file_results() {
+        let path = temp_file("result.log", b"12345");
+        let path_str = path.to_string_lossy().to_string();
+
+        let ok = upload_result(&path_str, "result.log".to_string(), Ok((5, "10001".to_string())));
+        assert!(ok.success);
+        assert_eq!(ok.attachment_id.as_deref(), Some("10001"));
+        assert_eq!(ok.error, None);
+
+        // 실패해도 파일 크기는 채워서 반환
+        let failed = upload_result(&path_str, "result.log".to_string(), Err("권한 없음".to_string()));
+        fs::remove_file(&path).ok();
+        assert!(!failed.success);
+        assert_eq!(failed.size, 5);
+        assert_eq!(failed.attachment_id, None);
+        assert_eq!(failed.error.as_deref(), Some("권한 없음"));
+
+        let missing = upload_result("/nonexistent/a.png", "a.png".to_string(), Err("파일 열기 실패".to_string()));
+        assert_eq!(missing.size, 0);
+        assert_eq!(missing.path, "/nonexistent/a.png");
+    }
+}
@@ -0,0 +1,483 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::http_client;
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_custom_fields::FieldDescriptor;
+use crate::jira_fields::{self, IssueFieldOptions, LookupCache};
+use crate::jira_links;
+use crate::jira_meta;
+use crate::{build_issue_fields, IssueAnalysis, JiraConfig};
+
+// 스프레드시트/JSON 백로그 일괄 이슈 생성
+// 1) 모든 행을 createmeta 기준으로 먼저 검증하고 2) 통과한 행만 bulk API로 50개씩 생성합니다.
+const BATCH_SIZE: usize = 50;
+const MAX_ROWS: usize = 5000;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BulkIssueRecord {
+    #[serde(flatten)]
+    pub analysis: IssueAnalysis,
+    #[serde(default)]
+    pub options: IssueFieldOptions,
+}
+
+// 행별 결과 (row는 1부터 시작, CSV는 머리글 다음 행이 1)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BulkRowResult {
+    pub row: usize,
+    pub title: String,
+    pub success: bool,
+    pub key: Option<String>,
+    pub error: Option<String>,
+}
+
+// 열 이름 자동 인식용 별칭 (대상 필드 → 열 이름)
+const STANDARD_COLUMNS: &[(&str, &[&str])] = &[
+    ("title", &["title", "summary", "제목", "요약"]),
+    ("description", &["description", "설명", "본문"]),
+    ("issue_type", &["issue_type", "issue type", "issuetype", "type", "이슈 타입", "유형"]),
+    ("priority", &["priority", "우선순위"]),
+    ("labels", &["labels", "label", "라벨", "레이블"]),
+    ("assignee", &["assignee", "담당자"]),
+    ("reporter", &["reporter", "보고자"]),
+    ("components", &["components", "component", "컴포넌트"]),
+    ("fix_versions", &["fix_versions", "fix version", "fix versions", "fixversions", "수정 버전"]),
+    ("due_date", &["due_date", "due date", "duedate", "기한"]),
+    ("parent", &["parent", "parent key", "상위 이슈"]),
+];
+
+fn normalize(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+fn standard_target(name: &str) -> Option<&'static str> {
+    let name = normalize(name);
+    STANDARD_COLUMNS
+        .iter()
+        .find(|(target, aliases)| *target == name || aliases.contains(&name.as_str()))
+        .map(|(target, _)| *target)
+}
+
+fn value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::Array(items) => items.iter().map(value_to_string).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    }
+}
+
+fn split_list(value: &serde_json::Value, separators: &[char]) -> Vec<String> {
+    value_to_string(value)
+        .split(|c| separators.contains(&c))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+// 열 → 대상 필드 매핑을 적용해 한 행을 레코드로 변환
+// 매핑에 없는 열은 표준 별칭과 customfield_ 접두어로만 자동 인식합니다.
+fn row_to_record(
+    row: &serde_json::Map<String, serde_json::Value>,
+    column_mapping: &HashMap<String, String>,
+) -> Result<BulkIssueRecord, String> {
+    let mut analysis = IssueAnalysis {
+        title: String::new(),
+        description: String::new(),
+        issue_type: "Task".to_string(),
+        priority: "Medium".to_string(),
+        labels: vec![],
+        confidence: None,
+        sections: None,
+    };
+    let mut options = IssueFieldOptions::default();
+
+    for (column, value) in row {
+        let mapped = column_mapping
+            .iter()
+            .find(|(from, _)| normalize(from) == normalize(column))
+            .map(|(_, to)| to.as_str());
+        let target = mapped.unwrap_or(column);
+
+        let text = value_to_string(value);
+        match standard_target(target) {
+            Some("title") => analysis.title = text,
+            Some("description") => analysis.description = text,
+            Some("issue_type") if !text.is_empty() => analysis.issue_type = text,
+            Some("priority") if !text.is_empty() => analysis.priority = text,
+            // Jira 라벨에는 공백을 쓸 수 없으므로 공백도 구분자로 처리
+            Some("labels") => analysis.labels = split_list(value, &[',', ' ', ';']),
+            Some("assignee") => options.assignee = Some(text).filter(|t| !t.is_empty()),
+            Some("reporter") => options.reporter = Some(text).filter(|t| !t.is_empty()),
+            Some("components") => options.components = split_list(value, &[',', ';']),
+            Some("fix_versions") => options.fix_versions = split_list(value, &[',', ';']),
+            Some("due_date") => options.due_date = Some(text).filter(|t| !t.is_empty()),
+            Some("parent") => options.parent = Some(text).filter(|t| !t.is_empty()),
+            Some(_) => {}
+            None if mapped.is_some() || target.starts_with("customfield_") => {
+                options.custom_fields.insert(target.to_string(), value.clone());
+            }
+            None => {}
+        }
+    }
+
+    if analysis.title.trim().is_empty() {
+        return Err("제목(title/summary) 값이 없습니다.".to_string());
+    }
+    Ok(BulkIssueRecord { analysis, options })
+}
+
+fn read_rows(path: &Path) -> Result<Vec<serde_json::Map<String, serde_json::Value>>, String> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "csv" => {
+            let mut reader = csv::ReaderBuilder::new()
+                .flexible(true)
+                .from_path(path)
+                .map_err(|e| format!("CSV 파일 열기 실패: {}", e))?;
+            let headers: Vec<String> = reader
+                .headers()
+                .map_err(|e| format!("CSV 머리글 읽기 실패: {}", e))?
+                .iter()
+                .map(|h| h.trim_start_matches('\u{feff}').trim().to_string())
+                .collect();
+
+            reader
+                .records()
+                .map(|record| {
+                    let record = record.map_err(|e| format!("CSV 읽기 실패: {}", e))?;
+                    Ok(headers
+                        .iter()
+                        .zip(record.iter())
+                        .map(|(header, value)| (header.clone(), serde_json::json!(value)))
+                        .collect())
+                })
+                .collect()
+        }
+        "json" => {
+            let content = fs::read_to_string(path).map_err(|e| format!("JSON 파일 읽기 실패: {}", e))?;
+            let rows: Vec<serde_json::Value> =
+                serde_json::from_str(&content).map_err(|e| format!("JSON 파싱 오류 (객체 배열이어야 합니다): {}", e))?;
+            rows.into_iter()
+                .enumerate()
+                .map(|(i, row)| match row {
+                    serde_json::Value::Object(map) => Ok(map),
+                    _ => Err(format!("{}번째 항목이 객체가 아닙니다.", i + 1)),
+                })
+                .collect()
+        }
+        _ => Err(format!("지원하지 않는 파일 형식입니다: {} (csv 또는 json)", path.display())),
+    }
+}
+
+// 파일의 각 행을 레코드로 변환 (변환 실패는 행별 오류로 남김)
+pub fn load_records(path: &str, column_mapping: &HashMap<String, String>) -> Result<Vec<Result<BulkIssueRecord, String>>, String> {
+    let rows = read_rows(Path::new(path))?;
+    if rows.len() > MAX_ROWS {
+        return Err(format!("한 번에 최대 {}개 행까지 생성할 수 있습니다. (현재 {}개)", MAX_ROWS, rows.len()));
+    }
+    Ok(rows.iter().map(|row| row_to_record(row, column_mapping)).collect())
+}
+
+fn failed(row: usize, title: &str, error: String) -> BulkRowResult {
+    BulkRowResult {
+        row,
+        title: title.to_string(),
+        success: false,
+        key: None,
+        error: Some(error),
+    }
+}
+
+// bulk API 오류 항목을 배치 내 순번 → 메시지로 변환
+fn parse_bulk_errors(response: &serde_json::Value) -> HashMap<usize, String> {
+    response["errors"]
+        .as_array()
+        .map(|errors| {
+            errors
+                .iter()
+                .filter_map(|error| {
+                    let index = error["failedElementNumber"].as_u64()? as usize;
+                    let status = reqwest::StatusCode::from_u16(error["status"].as_u64().unwrap_or(400) as u16)
+                        .unwrap_or(reqwest::StatusCode::BAD_REQUEST);
+                    Some((index, jira_fields::describe_error(status, &error["elementErrors"].to_string())))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+async fn create_batch(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    batch: &[serde_json::Map<String, serde_json::Value>],
+) -> Result<Vec<Result<String, String>>, String> {
+    let url = jira_api::api_url(config, "issue/bulk");
+    let updates: Vec<serde_json::Value> = batch.iter().map(|fields| serde_json::json!({ "fields": fields })).collect();
+
+    let response = client
+        .post(&url)
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .timeout(http_client::BULK_TIMEOUT)
+        .json(&serde_json::json!({ "issueUpdates": updates }))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    // 일부만 실패해도 201이 아닌 상태로 응답하므로 본문을 먼저 확인
+    let status = response.status();
+    let response_text = response.text().await.unwrap_or_default();
+    let body = match serde_json::from_str::<serde_json::Value>(&response_text) {
+        Ok(body) if body["issues"].is_array() || body["errors"].is_array() => body,
+        _ => return Err(jira_fields::describe_error(status, &response_text)),
+    };
+
+    Ok(batch_outcomes(&body, batch.len()))
+}
+
+// bulk 응답을 배치 내 요청 순서별 결과(이슈 키 또는 오류)로 변환
+fn batch_outcomes(body: &serde_json::Value, batch_len: usize) -> Vec<Result<String, String>> {
+    let errors = parse_bulk_errors(body);
+    let mut created = body["issues"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|issue| issue["key"].as_str().unwrap_or("").to_string());
+
+    // 생성된 이슈는 실패하지 않은 요청 순서대로 반환됩니다.
+    (0..batch_len)
+        .map(|i| match errors.get(&i) {
+            Some(error) => Err(error.clone()),
+            None => created
+                .next()
+                .ok_or_else(|| "응답에서 이슈 키를 찾을 수 없습니다".to_string()),
+        })
+        .collect()
+}
+
+pub async fn bulk_create(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    records: Vec<Result<BulkIssueRecord, String>>,
+) -> Result<Vec<BulkRowResult>, String> {
+    if records.is_empty() {
+        return Ok(vec![]);
+    }
+    if records.len() > MAX_ROWS {
+        return Err(format!("한 번에 최대 {}개 행까지 생성할 수 있습니다.", MAX_ROWS));
+    }
+
+    // 1단계: 전체 행 검증 (생성 화면 필드는 이슈 타입별로, 우선순위/사용자/컴포넌트/버전은 실행마다 한 번만 조회)
+    let issue_types = jira_meta::fetch_issue_types(client, config).await?;
+    let mut descriptor_cache: HashMap<String, Vec<FieldDescriptor>> = HashMap::new();
+    let mut lookups = LookupCache::default();
+    let mut results = Vec::new();
+    let mut valid = Vec::new();
+
+    for (index, record) in records.into_iter().enumerate() {
+        let row = index + 1;
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                results.push(failed(row, "", e));
+                continue;
+            }
+        };
+        let fields = build_issue_fields(
+            client,
+            config,
+            &record.analysis,
+            &record.options,
+            &issue_types,
+            &mut descriptor_cache,
+            &mut lookups,
+        )
+        .await;
+        match fields {
+            Ok(fields) => valid.push((row, record, fields)),
+            Err(e) => results.push(failed(row, &record.analysis.title, e)),
+        }
+    }
+    println!("일괄 생성 검증 완료: 통과 {}건, 실패 {}건", valid.len(), results.len());
+
+    // 2단계: 통과한 행만 배치 생성 후 연결 추가
+    for batch in valid.chunks(BATCH_SIZE) {
+        let fields: Vec<_> = batch.iter().map(|(_, _, fields)| fields.clone()).collect();
+        let outcomes = match create_batch(client, config, &fields).await {
+            Ok(outcomes) => outcomes,
+            Err(e) => vec![Err(e); batch.len()],
+        };
+
+        for ((row, record, _), outcome) in batch.iter().zip(outcomes) {
+            let title = &record.analysis.title;
+            let result = match outcome {
+                Ok(key) => match jira_links::link_issues(client, config, &key, &record.options.links).await {
+                    Ok(()) => BulkRowResult {
+                        row: *row,
+                        title: title.clone(),
+                        success: true,
+                        key: Some(key),
+                        error: None,
+                    },
+                    Err(e) => BulkRowResult {
+                        row: *row,
+                        title: title.clone(),
+                        success: true,
+                        error: Some(format!("{} 이슈는 생성되었지만 일부 연결에 실패했습니다. {}", key, e)),
+                        key: Some(key),
+                    },
+                },
+                Err(e) => failed(*row, title, e),
+            };
+            results.push(result);
+        }
+    }
+
+    results.sort_by_key(|r| r.row);
+    let created = results.iter().filter(|r| r.success).count();
+    println!("일괄 생성 완료: {}건 생성, {}건 실패", created, results.len() - created);
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(value: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    fn temp_file(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("pengu_bulk_{}_{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn maps_columns_by_alias_and_mapping() {
+        let mapping = HashMap::from([
+            ("Story Points".to_string(), "customfield_10016".to_string()),
+            ("담당".to_string(), "assignee".to_string()),
+        ]);
+        let record = row_to_record(
+            &row(json!({
+                "요약": "로그인 실패",
+                "설명": "본문",
+                "Issue Type": "Bug",
+                "우선순위": "",
+                "라벨": "auth, login web",
+                "담당": "kim",
+                "Component": "Web; API",
+                "Due Date": "2026-01-31",
+                "Story Points": 3,
+                "customfield_10020": "sprint",
+                "메모": "무시되는 열",
+            })),
+            &mapping,
+        )
+        .unwrap();
+
+        assert_eq!(record.analysis.title, "로그인 실패");
+        assert_eq!(record.analysis.description, "본문");
+        assert_eq!(record.analysis.issue_type, "Bug");
+        // 빈 값은 기본값 유지
+        assert_eq!(record.analysis.priority, "Medium");
+        assert_eq!(record.analysis.labels, vec!["auth", "login", "web"]);
+        assert_eq!(record.options.assignee.as_deref(), Some("kim"));
+        assert_eq!(record.options.components, vec!["Web", "API"]);
+        assert_eq!(record.options.due_date.as_deref(), Some("2026-01-31"));
+        assert_eq!(record.options.custom_fields.get("customfield_10016"), Some(&json!(3)));
+        assert_eq!(record.options.custom_fields.get("customfield_10020"), Some(&json!("sprint")));
+        assert_eq!(record.options.custom_fields.len(), 2);
+    }
+
+    #[test]
+    fn rejects_rows_without_title() {
+        let cases = [json!({ "description": "본문만" }), json!({ "summary": "  ", "priority": "High" })];
+        for case in cases {
+            let error = row_to_record(&row(case.clone()), &HashMap::new()).unwrap_err();
+            assert!(error.contains("제목"), "입력: {}", case);
+        }
+    }
+
+    #[test]
+    fn reads_csv_and_json_rows() {
+        let csv_path = temp_file("rows.csv", "\u{feff}Summary,Labels\n첫 번째,a b\n두 번째,\n");
+        let rows = read_rows(&csv_path).unwrap();
+        fs::remove_file(&csv_path).ok();
+        assert_eq!(rows.len(), 2);
+        // BOM이 붙은 머리글도 인식
+        assert_eq!(rows[0]["Summary"], json!("첫 번째"));
+        assert_eq!(rows[1]["Labels"], json!(""));
+
+        let json_path = temp_file("rows.json", r#"[{"title": "A", "labels": ["x", "y"]}, {"title": "B"}]"#);
+        let rows = read_rows(&json_path).unwrap();
+        fs::remove_file(&json_path).ok();
+        assert_eq!(rows.len(), 2);
+        let record = row_to_record(&rows[0], &HashMap::new()).unwrap();
+        assert_eq!(record.analysis.labels, vec!["x", "y"]);
+
+        let invalid_path = temp_file("invalid.json", r#"[{"title": "A"}, "B"]"#);
+        let error = read_rows(&invalid_path).unwrap_err();
+        fs::remove_file(&invalid_path).ok();
+        assert!(error.contains("2번째"));
+
+        assert!(read_rows(Path::new("rows.xlsx")).unwrap_err().contains("지원하지 않는"));
+    }
+
+    #[test]
+    fn maps_bulk_errors_to_batch_positions() {
+        let body = json!({
+            "issues": [{ "key": "TEST-1" }, { "key": "TEST-2" }],
+            "errors": [{
+                "status": 400,
+                "failedElementNumber": 1,
+                "elementErrors": { "errors": { "priority": "잘못된 우선순위" } }
+            }]
+        });
+        let errors = parse_bulk_errors(&body);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[&1].contains("잘못된 우선순위"));
+
+        let outcomes = batch_outcomes(&body, 3);
+        assert_eq!(outcomes[0], Ok("TEST-1".to_string()));
+        assert!(outcomes[1].is_err());
+        assert_eq!(outcomes[2], Ok("TEST-2".to_string()));
+    }
+
+    #[test]
+    fn splits_rows_into_batches_and_keeps_row_numbers() {
+        // 검증 실패한 3행을 건너뛴 51개 행
+        let valid: Vec<usize> = (1..=52).filter(|row| *row != 3).collect();
+        let batches: Vec<&[usize]> = valid.chunks(BATCH_SIZE).collect();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), 50);
+        assert_eq!(batches[1], &[52]);
+
+        // 첫 배치의 세 번째 요청(4행)이 실패한 응답
+        let body = json!({
+            "issues": (0..49).map(|i| json!({ "key": format!("TEST-{}", i + 1) })).collect::<Vec<_>>(),
+            "errors": [{ "status": 400, "failedElementNumber": 2, "elementErrors": {} }]
+        });
+        let results: Vec<(usize, Result<String, String>)> =
+            batches[0].iter().copied().zip(batch_outcomes(&body, batches[0].len())).collect();
+        assert_eq!(results[1], (2, Ok("TEST-2".to_string())));
+        assert_eq!(results[2].0, 4);
+        assert!(results[2].1.is_err());
+        assert_eq!(results[3], (5, Ok("TEST-3".to_string())));
+        assert_eq!(results[49], (51, Ok("TEST-49".to_string())));
+    }
+}
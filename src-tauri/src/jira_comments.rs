@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_fields;
+use crate::JiraConfig;
+
+// 이슈 댓글 조회/작성 (본문은 Markdown ↔ ADF 또는 위키 마크업 변환)
+const PAGE_SIZE: usize = 50;
+const MAX_LIMIT: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentAuthor {
+    pub account_id: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraComment {
+    pub id: String,
+    pub author: Option<CommentAuthor>,
+    // Markdown
+    pub body: String,
+    pub body_text: String,
+    pub created: String,
+    pub updated: String,
+    pub visibility: Option<CommentVisibility>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JiraCommentPage {
+    pub comments: Vec<JiraComment>,
+    // 이슈의 전체 댓글 수
+    pub total: usize,
+    // 한도에 도달해 남은 댓글이 있으면 다음 호출에 넘길 start_at
+    pub next_start_at: Option<usize>,
+}
+
+// 댓글 공개 범위 (프로젝트 역할 또는 그룹)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentVisibility {
+    // role 또는 group
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub value: String,
+}
+
+fn comments_url(config: &JiraConfig, issue_key: &str) -> String {
+    jira_api::api_url(config, &format!("issue/{}/comment", issue_key))
+}
+
+fn parse_author(author: &serde_json::Value) -> Option<CommentAuthor> {
+    Some(CommentAuthor {
+        // Server/Data Center에는 accountId 대신 사용자 이름(name)이 있습니다.
+        account_id: author["accountId"].as_str().or_else(|| author["name"].as_str())?.to_string(),
+        display_name: author["displayName"].as_str().unwrap_or("").to_string(),
+        avatar_url: author["avatarUrls"]["48x48"].as_str().map(|url| url.to_string()),
+    })
+}
+
+fn parse_comment(comment: &serde_json::Value) -> Option<JiraComment> {
+    Some(JiraComment {
+        id: comment["id"].as_str()?.to_string(),
+        author: parse_author(&comment["author"]),
+        body: jira_api::rich_text_to_markdown(&comment["body"]),
+        body_text: jira_api::rich_text_to_plain_text(&comment["body"]),
+        created: comment["created"].as_str().unwrap_or("").to_string(),
+        updated: comment["updated"].as_str().unwrap_or("").to_string(),
+        visibility: serde_json::from_value(comment["visibility"].clone()).ok(),
+    })
+}
+
+// start_at부터 limit개까지 페이지를 넘기며 오래된 순으로 모읍니다.
+pub async fn list_comments(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    start_at: Option<usize>,
+    limit: Option<usize>,
+) -> Result<JiraCommentPage, String> {
+    let url = comments_url(config, issue_key);
+    let limit = limit.unwrap_or(MAX_LIMIT).clamp(1, MAX_LIMIT);
+    let mut comments = Vec::new();
+    let mut start_at = start_at.unwrap_or(0);
+    let mut total;
+
+    loop {
+        let response = client
+            .get(&url)
+            .jira_auth(config)?
+            .header("Accept", "application/json")
+            .query(&[
+                ("startAt", start_at.to_string()),
+                ("maxResults", PAGE_SIZE.min(limit - comments.len()).to_string()),
+                ("orderBy", "created".to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(jira_fields::describe_error(status, &error_text));
+        }
+
+        let page: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+        let items = page["comments"].as_array().cloned().unwrap_or_default();
+        let fetched = items.len();
+        comments.extend(items.iter().filter_map(parse_comment));
+
+        total = page["total"].as_u64().unwrap_or(0) as usize;
+        start_at += fetched;
+        if fetched == 0 || start_at >= total || comments.len() >= limit {
+            break;
+        }
+    }
+
+    Ok(JiraCommentPage {
+        comments,
+        total,
+        next_start_at: Some(start_at).filter(|&next| next < total),
+    })
+}
+
+pub async fn add_comment(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    body: &str,
+    visibility: Option<CommentVisibility>,
+) -> Result<JiraComment, String> {
+    if body.trim().is_empty() {
+        return Err("댓글 내용을 입력해주세요.".to_string());
+    }
+
+    let mut request = serde_json::json!({ "body": jira_api::rich_text(config, body) });
+    if let Some(visibility) = visibility {
+        if visibility.kind != "role" && visibility.kind != "group" {
+            return Err(format!("댓글 공개 범위 유형은 role 또는 group이어야 합니다: {}", visibility.kind));
+        }
+        request["visibility"] = serde_json::json!({ "type": visibility.kind, "value": visibility.value });
+    }
+
+    let response = client
+        .post(comments_url(config, issue_key))
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        println!("Jira 댓글 작성 오류 ({}): {}", status, error_text);
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    let created: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+    println!("Jira 댓글 작성 완료: {}", issue_key);
+    parse_comment(&created).ok_or_else(|| "응답에서 댓글 정보를 찾을 수 없습니다".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_cloud_comment_with_account_id() {
+        let comment = parse_comment(&json!({
+            "id": "10001",
+            "author": {
+                "accountId": "5b10a2844c20165700ede21g",
+                "displayName": "김개발",
+                "avatarUrls": { "48x48": "https://example.com/a.png" }
+            },
+            "body": {
+                "type": "doc",
+                "version": 1,
+                "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "재현됩니다" }] }]
+            },
+            "created": "2024-05-01T10:20:30.123+0900",
+            "updated": "2024-05-01T11:00:00.000+0900",
+            "visibility": { "type": "role", "value": "Developers", "identifier": "Developers" }
+        }))
+        .unwrap();
+
+        let author = comment.author.unwrap();
+        assert_eq!(author.account_id, "5b10a2844c20165700ede21g");
+        assert_eq!(author.display_name, "김개발");
+        assert_eq!(author.avatar_url.as_deref(), Some("https://example.com/a.png"));
+        assert_eq!(comment.body.trim(), "재현됩니다");
+        assert_eq!(comment.body_text.trim(), "재현됩니다");
+        assert_eq!(comment.updated, "2024-05-01T11:00:00.000+0900");
+
+        let visibility = comment.visibility.unwrap();
+        assert_eq!(visibility.kind, "role");
+        assert_eq!(visibility.value, "Developers");
+    }
+
+    #[test]
+    fn parses_server_comment_with_user_name() {
+        let comment = parse_comment(&json!({
+            "id": "20002",
+            "author": { "name": "kim", "key": "JIRAUSER10000", "displayName": "Kim" },
+            "body": "*굵게* 확인",
+            "created": "2024-05-01T10:20:30.123+0900",
+            "visibility": { "type": "group", "value": "jira-developers" }
+        }))
+        .unwrap();
+
+        let author = comment.author.unwrap();
+        assert_eq!(author.account_id, "kim");
+        assert_eq!(author.avatar_url, None);
+        assert_eq!(comment.body.trim(), "**굵게** 확인");
+        assert_eq!(comment.body_text.trim(), "굵게 확인");
+        assert_eq!(comment.updated, "");
+        assert_eq!(comment.visibility.unwrap().kind, "group");
+    }
+
+    #[test]
+    fn parses_missing_author_and_visibility() {
+        let comment = parse_comment(&json!({ "id": "3", "body": "" })).unwrap();
+        assert!(comment.author.is_none());
+        assert!(comment.visibility.is_none());
+
+        // 공개 범위 형식이 다르면 무시
+        let comment = parse_comment(&json!({ "id": "4", "author": {}, "visibility": { "value": "x" } })).unwrap();
+        assert!(comment.author.is_none());
+        assert!(comment.visibility.is_none());
+
+        assert!(parse_comment(&json!({ "body": "id 없음" })).is_none());
+    }
+}
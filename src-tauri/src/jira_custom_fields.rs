@@ -0,0 +1,556 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_fields::{self, LookupCache};
+use crate::JiraConfig;
+
+// createmeta 필드 스키마를 기반으로 커스텀 필드 값을 검증하고 Jira 형식으로 변환합니다.
+const PAGE_SIZE: usize = 50;
+const TEXTAREA_CUSTOM_TYPE: &str = "com.atlassian.jira.plugin.system.customfieldtypes:textarea";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldKind {
+    Text,
+    TextArea,
+    Number,
+    Date,
+    DateTime,
+    Option,
+    MultiOption,
+    User,
+    MultiUser,
+    StringList,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllowedValue {
+    pub id: String,
+    pub value: String,
+}
+
+// 프론트엔드에서 입력 폼을 구성하기 위한 필드 설명
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldDescriptor {
+    pub id: String,
+    pub name: String,
+    pub required: bool,
+    pub custom: bool,
+    pub kind: FieldKind,
+    pub allowed_values: Vec<AllowedValue>,
+    // 값을 보내지 않으면 Jira가 기본값을 채우는 필드
+    #[serde(default)]
+    pub has_default_value: bool,
+}
+
+fn field_kind(schema: &serde_json::Value) -> FieldKind {
+    let schema_type = schema["type"].as_str().unwrap_or("");
+    let items = schema["items"].as_str().unwrap_or("");
+    let custom = schema["custom"].as_str().unwrap_or("");
+
+    match (schema_type, items) {
+        ("string", _) if custom == TEXTAREA_CUSTOM_TYPE => FieldKind::TextArea,
+        ("string", _) => FieldKind::Text,
+        ("number", _) => FieldKind::Number,
+        ("date", _) => FieldKind::Date,
+        ("datetime", _) => FieldKind::DateTime,
+        ("option", _) => FieldKind::Option,
+        ("user", _) => FieldKind::User,
+        ("array", "option") => FieldKind::MultiOption,
+        ("array", "user") => FieldKind::MultiUser,
+        ("array", "string") => FieldKind::StringList,
+        _ => FieldKind::Other,
+    }
+}
+
+fn parse_descriptor(field: &serde_json::Value) -> Option<FieldDescriptor> {
+    let id = field["fieldId"].as_str().or_else(|| field["key"].as_str())?.to_string();
+
+    let allowed_values = field["allowedValues"]
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|v| {
+                    let value = v["value"].as_str().or_else(|| v["name"].as_str())?;
+                    Some(AllowedValue {
+                        id: v["id"].as_str()?.to_string(),
+                        value: value.to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(FieldDescriptor {
+        name: field["name"].as_str().unwrap_or(&id).to_string(),
+        custom: id.starts_with("customfield_"),
+        required: field["required"].as_bool().unwrap_or(false),
+        kind: field_kind(&field["schema"]),
+        has_default_value: field["hasDefaultValue"].as_bool().unwrap_or(false),
+        allowed_values,
+        id,
+    })
+}
+
+pub async fn fetch_create_fields(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_type_id: &str,
+) -> Result<Vec<FieldDescriptor>, String> {
+    let url = jira_api::api_url(
+        config,
+        &format!("issue/createmeta/{}/issuetypes/{}", config.project_key, issue_type_id),
+    );
+
+    let mut descriptors = Vec::new();
+    let mut start_at = 0;
+
+    loop {
+        let response = client
+            .get(&url)
+            .jira_auth(config)?
+            .header("Accept", "application/json")
+            .query(&[("startAt", start_at), ("maxResults", PAGE_SIZE)])
+            .send()
+            .await
+            .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("필드 메타데이터 조회 실패 ({}): {}", status, error_text));
+        }
+
+        let page: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+        // Server/Data Center는 목록을 values로 반환
+        let fields = page["fields"].as_array().or_else(|| page["values"].as_array()).cloned().unwrap_or_default();
+        let fetched = fields.len();
+        descriptors.extend(fields.iter().filter_map(parse_descriptor));
+
+        let total = page["total"].as_u64().unwrap_or(0) as usize;
+        start_at += fetched;
+        if fetched == 0 || start_at >= total {
+            break;
+        }
+    }
+
+    Ok(descriptors)
+}
+
+// 기존 이슈에서 수정 가능한 필드 (editmeta)
+pub async fn fetch_edit_fields(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+) -> Result<Vec<FieldDescriptor>, String> {
+    let url = jira_api::api_url(config, &format!("issue/{}/editmeta", issue_key));
+
+    let response = client
+        .get(&url)
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(format!("수정 메타데이터 조회 실패 ({}): {}", status, error_text));
+    }
+
+    let meta: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+    Ok(parse_field_map(&meta["fields"]))
+}
+
+// editmeta, 전환 화면처럼 필드 id → 필드 정보 객체 형태인 응답을 변환
+pub fn parse_field_map(fields: &serde_json::Value) -> Vec<FieldDescriptor> {
+    fields
+        .as_object()
+        .map(|fields| {
+            fields
+                .iter()
+                .filter_map(|(id, field)| {
+                    let mut field = field.clone();
+                    if field["key"].is_null() && field["fieldId"].is_null() {
+                        field["key"] = serde_json::json!(id);
+                    }
+                    parse_descriptor(&field)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn find_descriptor<'a>(descriptors: &'a [FieldDescriptor], key: &str) -> Option<&'a FieldDescriptor> {
+    let key_lower = key.trim().to_lowercase();
+    descriptors
+        .iter()
+        .find(|d| d.id == key.trim())
+        .or_else(|| descriptors.iter().find(|d| d.name.to_lowercase() == key_lower))
+}
+
+fn value_as_string(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn value_as_list(value: &serde_json::Value) -> Vec<serde_json::Value> {
+    match value {
+        serde_json::Value::Array(items) => items.clone(),
+        serde_json::Value::String(s) => s
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| serde_json::json!(s))
+            .collect(),
+        serde_json::Value::Null => vec![],
+        other => vec![other.clone()],
+    }
+}
+
+fn resolve_option(descriptor: &FieldDescriptor, value: &serde_json::Value) -> Result<serde_json::Value, String> {
+    let wanted = value_as_string(value)
+        .ok_or_else(|| format!("'{}' 필드에는 선택지 값(문자열)이 필요합니다.", descriptor.name))?;
+
+    descriptor
+        .allowed_values
+        .iter()
+        .find(|v| v.id == wanted || v.value.eq_ignore_ascii_case(wanted.trim()))
+        .map(|v| serde_json::json!({ "id": v.id }))
+        .ok_or_else(|| {
+            let allowed: Vec<&str> = descriptor.allowed_values.iter().map(|v| v.value.as_str()).collect();
+            format!(
+                "'{}' 필드에 '{}' 값을 사용할 수 없습니다. 허용 값: {}",
+                descriptor.name,
+                wanted,
+                allowed.join(", ")
+            )
+        })
+}
+
+async fn serialize_value(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    descriptor: &FieldDescriptor,
+    value: &serde_json::Value,
+    lookups: &mut LookupCache,
+) -> Result<serde_json::Value, String> {
+    let type_error = |expected: &str| format!("'{}' 필드에는 {} 값이 필요합니다.", descriptor.name, expected);
+
+    match descriptor.kind {
+        FieldKind::Text => value_as_string(value).map(serde_json::Value::String).ok_or_else(|| type_error("문자열")),
+        FieldKind::TextArea => {
+            // v3 API의 여러 줄 텍스트 필드는 ADF 문서로, v2는 위키 마크업으로 전송해야 합니다.
+            let text = value_as_string(value).ok_or_else(|| type_error("문자열"))?;
+            Ok(jira_api::rich_text(config, &text))
+        }
+        FieldKind::Number => {
+            let number = match value {
+                serde_json::Value::Number(n) => n.as_f64(),
+                serde_json::Value::String(s) => s.trim().parse::<f64>().ok(),
+                _ => None,
+            }
+            .ok_or_else(|| type_error("숫자"))?;
+            Ok(serde_json::json!(number))
+        }
+        FieldKind::Date => {
+            let text = value_as_string(value).ok_or_else(|| type_error("날짜(YYYY-MM-DD)"))?;
+            chrono::NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").map_err(|_| type_error("날짜(YYYY-MM-DD)"))?;
+            Ok(serde_json::json!(text.trim()))
+        }
+        FieldKind::DateTime => {
+            let text = value_as_string(value).ok_or_else(|| type_error("날짜/시간(ISO 8601)"))?;
+            let parsed = chrono::DateTime::parse_from_rfc3339(text.trim()).map_err(|_| type_error("날짜/시간(ISO 8601)"))?;
+            Ok(serde_json::json!(parsed.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string()))
+        }
+        FieldKind::Option => resolve_option(descriptor, value),
+        FieldKind::MultiOption => value_as_list(value)
+            .iter()
+            .map(|item| resolve_option(descriptor, item))
+            .collect::<Result<Vec<_>, _>>()
+            .map(serde_json::Value::Array),
+        FieldKind::User => {
+            let user = value_as_string(value).ok_or_else(|| type_error("사용자"))?;
+            let user_id = jira_fields::resolve_user_id(client, config, &user, lookups).await?;
+            Ok(jira_api::user_ref(config, Some(&user_id)))
+        }
+        FieldKind::MultiUser => {
+            let mut users = Vec::new();
+            for item in value_as_list(value) {
+                let user = value_as_string(&item).ok_or_else(|| type_error("사용자 목록"))?;
+                let user_id = jira_fields::resolve_user_id(client, config, &user, lookups).await?;
+                users.push(jira_api::user_ref(config, Some(&user_id)));
+            }
+            Ok(serde_json::Value::Array(users))
+        }
+        FieldKind::StringList => value_as_list(value)
+            .iter()
+            .map(|item| value_as_string(item).map(serde_json::Value::String))
+            .collect::<Option<Vec<_>>>()
+            .map(serde_json::Value::Array)
+            .ok_or_else(|| type_error("문자열 목록")),
+        // 알 수 없는 형식은 입력값을 그대로 전달
+        FieldKind::Other => Ok(value.clone()),
+    }
+}
+
+fn is_blank(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => true,
+        serde_json::Value::String(s) => s.trim().is_empty(),
+        serde_json::Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+// 커스텀 필드 값(필드 id 또는 이름 → 값)을 검증/변환하여 fields에 추가하고 오류 목록을 반환
+async fn serialize_values(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    descriptors: &[FieldDescriptor],
+    values: &HashMap<String, serde_json::Value>,
+    lookups: &mut LookupCache,
+    fields: &mut serde_json::Map<String, serde_json::Value>,
+    missing_message: &str,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for (key, value) in values {
+        if is_blank(value) {
+            continue;
+        }
+        let Some(descriptor) = find_descriptor(descriptors, key) else {
+            errors.push(format!("'{}' 필드는 {}", key, missing_message));
+            continue;
+        };
+        match serialize_value(client, config, descriptor, value, lookups).await {
+            Ok(serialized) => {
+                fields.insert(descriptor.id.clone(), serialized);
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    errors
+}
+
+fn into_result(errors: Vec<String>) -> Result<(), String> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("커스텀 필드 오류: {}", errors.join(" / ")))
+    }
+}
+
+// 이슈 생성용: 값 변환 후 필수 커스텀 필드 누락까지 확인
+pub async fn apply_to_fields(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    descriptors: &[FieldDescriptor],
+    values: &HashMap<String, serde_json::Value>,
+    lookups: &mut LookupCache,
+    fields: &mut serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    let mut errors =
+        serialize_values(client, config, descriptors, values, lookups, fields, "이 이슈 타입의 생성 화면에 없습니다.").await;
+
+    // 필수 커스텀 필드 누락 확인 (표준 필드는 생성 로직에서 채우고, 기본값이 있는 필드는 Jira가 채움)
+    for descriptor in descriptors.iter().filter(|d| d.custom && d.required && !d.has_default_value) {
+        if !fields.contains_key(&descriptor.id) {
+            errors.push(format!("필수 필드 '{}' ({})의 값이 없습니다.", descriptor.name, descriptor.id));
+        }
+    }
+
+    into_result(errors)
+}
+
+// 이슈 수정용: 바꾸려는 필드만 변환 (editmeta 기준)
+pub async fn apply_edits(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    descriptors: &[FieldDescriptor],
+    values: &HashMap<String, serde_json::Value>,
+    lookups: &mut LookupCache,
+    fields: &mut serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    into_result(serialize_values(client, config, descriptors, values, lookups, fields, "이 이슈에서 수정할 수 없습니다.").await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn offline_config() -> JiraConfig {
+        serde_json::from_value(json!({
+            "base_url": "http://127.0.0.1:9",
+            "email": "user@example.com",
+            "api_token": "token",
+            "project_key": "TEST"
+        }))
+        .unwrap()
+    }
+
+    fn descriptor(id: &str, kind: FieldKind) -> FieldDescriptor {
+        FieldDescriptor {
+            id: id.to_string(),
+            name: format!("{} 이름", id),
+            required: false,
+            custom: id.starts_with("customfield_"),
+            kind,
+            allowed_values: vec![
+                AllowedValue { id: "1".to_string(), value: "High".to_string() },
+                AllowedValue { id: "2".to_string(), value: "Low".to_string() },
+            ],
+            has_default_value: false,
+        }
+    }
+
+    #[test]
+    fn maps_schema_to_field_kind() {
+        let cases = [
+            (json!({ "type": "string" }), FieldKind::Text),
+            (json!({ "type": "string", "custom": TEXTAREA_CUSTOM_TYPE }), FieldKind::TextArea),
+            (json!({ "type": "number" }), FieldKind::Number),
+            (json!({ "type": "date" }), FieldKind::Date),
+            (json!({ "type": "datetime" }), FieldKind::DateTime),
+            (json!({ "type": "option" }), FieldKind::Option),
+            (json!({ "type": "user" }), FieldKind::User),
+            (json!({ "type": "array", "items": "option" }), FieldKind::MultiOption),
+            (json!({ "type": "array", "items": "user" }), FieldKind::MultiUser),
+            (json!({ "type": "array", "items": "string" }), FieldKind::StringList),
+            (json!({ "type": "array", "items": "component" }), FieldKind::Other),
+            (json!({ "type": "sd-customerrequesttype" }), FieldKind::Other),
+        ];
+        for (schema, kind) in cases {
+            assert_eq!(field_kind(&schema), kind, "스키마: {}", schema);
+        }
+    }
+
+    #[test]
+    fn parses_createmeta_and_field_map() {
+        let field = json!({
+            "fieldId": "customfield_10010",
+            "name": "심각도",
+            "required": true,
+            "hasDefaultValue": true,
+            "schema": { "type": "option" },
+            "allowedValues": [{ "id": "1", "value": "높음" }, { "id": "2", "name": "낮음" }, { "value": "id 없음" }]
+        });
+        let parsed = parse_descriptor(&field).unwrap();
+        assert_eq!(parsed.id, "customfield_10010");
+        assert!(parsed.custom && parsed.required && parsed.has_default_value);
+        assert_eq!(parsed.kind, FieldKind::Option);
+        let values: Vec<(&str, &str)> = parsed.allowed_values.iter().map(|v| (v.id.as_str(), v.value.as_str())).collect();
+        assert_eq!(values, [("1", "높음"), ("2", "낮음")]);
+
+        // editmeta는 필드 id가 객체 키로만 있음
+        let map = parse_field_map(&json!({ "labels": { "name": "Labels", "schema": { "type": "array", "items": "string" } } }));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[0].id, "labels");
+        assert!(!map[0].custom);
+        assert_eq!(map[0].kind, FieldKind::StringList);
+
+        assert!(parse_descriptor(&json!({ "name": "id 없음" })).is_none());
+    }
+
+    #[test]
+    fn resolves_options_by_id_or_value() {
+        let field = descriptor("customfield_1", FieldKind::Option);
+        assert_eq!(resolve_option(&field, &json!("2")), Ok(json!({ "id": "2" })));
+        assert_eq!(resolve_option(&field, &json!(" high ")), Ok(json!({ "id": "1" })));
+        let error = resolve_option(&field, &json!("Medium")).unwrap_err();
+        assert!(error.contains("허용 값: High, Low"), "{}", error);
+        assert!(resolve_option(&field, &json!(["High"])).is_err());
+    }
+
+    #[tokio::test]
+    async fn serializes_values_for_each_kind() {
+        let client = reqwest::Client::new();
+        let config = offline_config();
+        let account = "5b10ac8d82e05b22cc7d4ef5";
+        let cases = [
+            (FieldKind::Text, json!(42), Ok(json!("42"))),
+            (FieldKind::Number, json!(" 3.5 "), Ok(json!(3.5))),
+            (FieldKind::Date, json!("2024-05-01"), Ok(json!("2024-05-01"))),
+            (FieldKind::DateTime, json!("2024-05-01T09:30:00+09:00"), Ok(json!("2024-05-01T09:30:00.000+0900"))),
+            (FieldKind::Option, json!("low"), Ok(json!({ "id": "2" }))),
+            (FieldKind::MultiOption, json!("High, Low"), Ok(json!([{ "id": "1" }, { "id": "2" }]))),
+            (FieldKind::User, json!(account), Ok(json!({ "accountId": account }))),
+            (FieldKind::MultiUser, json!([account]), Ok(json!([{ "accountId": account }]))),
+            (FieldKind::StringList, json!("a, b"), Ok(json!(["a", "b"]))),
+            (FieldKind::Other, json!({ "raw": true }), Ok(json!({ "raw": true }))),
+            (FieldKind::Number, json!("많음"), Err("숫자")),
+            (FieldKind::Date, json!("05/01/2024"), Err("날짜(YYYY-MM-DD)")),
+            (FieldKind::Text, json!({ "x": 1 }), Err("문자열")),
+            (FieldKind::StringList, json!([1, {}]), Err("문자열 목록")),
+        ];
+
+        for (kind, value, expected) in cases {
+            let field = descriptor("customfield_1", kind.clone());
+            let result = serialize_value(&client, &config, &field, &value, &mut LookupCache::default()).await;
+            match expected {
+                Ok(expected) => assert_eq!(result, Ok(expected), "{:?} {}", kind, value),
+                Err(expected) => {
+                    let error = result.unwrap_err();
+                    assert!(error.contains(expected), "{:?} {}: {}", kind, value, error);
+                }
+            }
+        }
+
+        let textarea = descriptor("customfield_1", FieldKind::TextArea);
+        let document = serialize_value(&client, &config, &textarea, &json!("**굵게**"), &mut LookupCache::default())
+            .await
+            .unwrap();
+        assert_eq!(document["type"], "doc");
+    }
+
+    #[tokio::test]
+    async fn required_fields_with_defaults_are_not_missing() {
+        let mut required = descriptor("customfield_1", FieldKind::Text);
+        required.required = true;
+        let mut defaulted = descriptor("customfield_2", FieldKind::Text);
+        defaulted.required = true;
+        defaulted.has_default_value = true;
+        let descriptors = [required, defaulted];
+
+        let apply = |values: HashMap<String, serde_json::Value>| {
+            let descriptors = descriptors.clone();
+            async move {
+                let mut fields = serde_json::Map::new();
+                let client = reqwest::Client::new();
+                apply_to_fields(&client, &offline_config(), &descriptors, &values, &mut LookupCache::default(), &mut fields)
+                    .await
+                    .map(|_| fields)
+            }
+        };
+
+        let error = apply(HashMap::new()).await.unwrap_err();
+        assert!(error.contains("customfield_1"), "{}", error);
+        assert!(!error.contains("customfield_2"), "{}", error);
+
+        // 이름으로도 지정할 수 있음
+        let fields = apply(HashMap::from([("customfield_1 이름".to_string(), json!("값"))])).await.unwrap();
+        assert_eq!(fields["customfield_1"], "값");
+        assert!(!fields.contains_key("customfield_2"));
+
+        let error = apply(HashMap::from([("없는 필드".to_string(), json!("값"))])).await.unwrap_err();
+        assert!(error.contains("'없는 필드' 필드는 이 이슈 타입의 생성 화면에 없습니다."), "{}", error);
+    }
+}
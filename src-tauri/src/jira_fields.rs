@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_links::IssueLinkRequest;
+use crate::JiraConfig;
+
+// 이슈 생성 시 함께 지정하는 표준 필드
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IssueFieldOptions {
+    // accountId(Server: 사용자 이름), 이메일 또는 표시 이름
+    #[serde(default)]
+    pub assignee: Option<String>,
+    #[serde(default)]
+    pub reporter: Option<String>,
+    #[serde(default)]
+    pub components: Vec<String>,
+    #[serde(default)]
+    pub fix_versions: Vec<String>,
+    // YYYY-MM-DD
+    #[serde(default)]
+    pub due_date: Option<String>,
+    // 커스텀 필드 id 또는 이름 → 값 (createmeta 스키마에 따라 변환)
+    #[serde(default)]
+    pub custom_fields: HashMap<String, serde_json::Value>,
+    // 상위 이슈 키 (하위 작업의 부모 또는 에픽)
+    #[serde(default)]
+    pub parent: Option<String>,
+    // true면 분석된 타입 대신 프로젝트의 하위 작업 타입으로 parent 아래에 생성
+    #[serde(default)]
+    pub subtask: bool,
+    // 생성 후 추가할 이슈 연결
+    #[serde(default)]
+    pub links: Vec<IssueLinkRequest>,
+}
+
+// 분석 결과 우선순위 → 로케일별 Jira 기본 우선순위 이름
+const PRIORITY_ALIASES: &[(&str, &[&str])] = &[
+    ("critical", &["critical", "highest", "blocker", "가장 높음", "매우 높음", "긴급"]),
+    ("high", &["high", "major", "높음"]),
+    ("medium", &["medium", "normal", "보통", "중간"]),
+    ("low", &["low", "minor", "낮음"]),
+    ("lowest", &["lowest", "trivial", "가장 낮음"]),
+];
+
+// 일괄 생성처럼 같은 조회를 여러 번 하는 작업에서 재사용하는 조회 결과
+#[derive(Default)]
+pub struct LookupCache {
+    priorities: Option<Vec<serde_json::Value>>,
+    // 지정값(소문자) → 사용자 식별자
+    users: HashMap<String, String>,
+    // components/versions → 프로젝트 항목 목록
+    project_items: HashMap<String, Vec<serde_json::Value>>,
+}
+
+fn normalize(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+async fn get_json(client: &reqwest::Client, config: &JiraConfig, url: &str, query: &[(&str, &str)]) -> Result<serde_json::Value, String> {
+    let response = client
+        .get(url)
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .query(query)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(describe_error(status, &error_text));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))
+}
+
+// Jira 오류 응답의 errorMessages와 필드별 errors를 읽기 쉬운 메시지로 변환
+pub fn describe_error(status: reqwest::StatusCode, error_text: &str) -> String {
+    let body: serde_json::Value = match serde_json::from_str(error_text) {
+        Ok(body) => body,
+        Err(_) => return format!("Jira API 오류 ({}): {}", status, error_text),
+    };
+
+    let mut messages: Vec<String> = body["errorMessages"]
+        .as_array()
+        .map(|list| list.iter().filter_map(|m| m.as_str().map(|m| m.to_string())).collect())
+        .unwrap_or_default();
+
+    if let Some(errors) = body["errors"].as_object() {
+        let mut field_errors: Vec<(&String, &serde_json::Value)> = errors.iter().collect();
+        field_errors.sort_by(|a, b| a.0.cmp(b.0));
+        messages.extend(
+            field_errors
+                .into_iter()
+                .map(|(field, message)| format!("{}: {}", field, message.as_str().unwrap_or(&message.to_string()))),
+        );
+    }
+
+    if messages.is_empty() {
+        format!("Jira API 오류 ({}): {}", status, error_text)
+    } else {
+        format!("Jira API 오류 ({}): {}", status, messages.join(", "))
+    }
+}
+
+// 우선순위 이름을 Jira 우선순위 id로 변환 (찾지 못하면 None: 프로젝트 기본값 사용)
+pub async fn resolve_priority(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    priority: &str,
+    lookups: &mut LookupCache,
+) -> Result<Option<String>, String> {
+    if priority.trim().is_empty() {
+        return Ok(None);
+    }
+
+    if lookups.priorities.is_none() {
+        let priorities = get_json(client, config, &jira_api::api_url(config, "priority"), &[]).await?;
+        lookups.priorities = Some(priorities.as_array().cloned().unwrap_or_default());
+    }
+    let priorities = lookups.priorities.as_deref().unwrap_or_default();
+
+    let wanted = normalize(priority);
+    let aliases = PRIORITY_ALIASES
+        .iter()
+        .find(|(_, names)| names.contains(&wanted.as_str()))
+        .map(|(_, names)| *names)
+        .unwrap_or(&[]);
+
+    let find = |matches: &dyn Fn(&str) -> bool| {
+        priorities
+            .iter()
+            .find(|p| p["name"].as_str().is_some_and(|name| matches(&normalize(name))))
+            .and_then(|p| p["id"].as_str())
+            .map(|id| id.to_string())
+    };
+
+    let found = find(&|name| name == wanted).or_else(|| find(&|name| aliases.contains(&name)));
+    if found.is_none() {
+        println!("우선순위 '{}'을(를) Jira에서 찾을 수 없어 기본값을 사용합니다.", priority);
+    }
+    Ok(found)
+}
+
+// 사용자 지정값을 사용자 식별자로 변환 (Cloud: accountId, Server/Data Center: 사용자 이름)
+pub async fn resolve_user_id(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    user: &str,
+    lookups: &mut LookupCache,
+) -> Result<String, String> {
+    let user = user.trim();
+    let server = jira_api::is_server(config);
+
+    // Jira Cloud accountId 형식 (예: 5b10ac8d82e05b22cc7d4ef5, 557058:f58131cb-...)
+    let looks_like_account_id = !user.contains('@')
+        && !user.contains(' ')
+        && user.len() >= 20
+        && user.chars().all(|c| c.is_ascii_alphanumeric() || c == ':' || c == '-');
+    if !server && looks_like_account_id {
+        return Ok(user.to_string());
+    }
+
+    let wanted = normalize(user);
+    if let Some(user_id) = lookups.users.get(&wanted) {
+        return Ok(user_id.clone());
+    }
+
+    // Server/Data Center의 사용자 검색은 query 대신 username 파라미터를 사용
+    let query_param = if server { "username" } else { "query" };
+    let users = get_json(client, config, &jira_api::api_url(config, "user/search"), &[(query_param, user)]).await?;
+    let users = users.as_array().cloned().unwrap_or_default();
+
+    let exact = users.iter().find(|u| {
+        u["emailAddress"].as_str().is_some_and(|email| normalize(email) == wanted)
+            || u["displayName"].as_str().is_some_and(|name| normalize(name) == wanted)
+            || u["name"].as_str().is_some_and(|name| normalize(name) == wanted)
+    });
+
+    let matched = match (exact, users.len()) {
+        (Some(found), _) => found,
+        (None, 1) => &users[0],
+        (None, 0) => return Err(format!("사용자 '{}'을(를) 찾을 수 없습니다.", user)),
+        (None, count) => {
+            return Err(format!(
+                "사용자 '{}'에 해당하는 사용자가 {}명입니다. 이메일이나 accountId로 지정해주세요.",
+                user, count
+            ))
+        }
+    };
+
+    let id_field = if server { "name" } else { "accountId" };
+    let user_id = matched[id_field]
+        .as_str()
+        .map(|id| id.to_string())
+        .ok_or_else(|| format!("사용자 '{}'의 {}를 찾을 수 없습니다.", user, id_field))?;
+    lookups.users.insert(wanted, user_id.clone());
+    Ok(user_id)
+}
+
+// 이름 목록을 프로젝트 컴포넌트/버전 id로 변환
+async fn resolve_project_items(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    resource: &str,
+    label: &str,
+    names: &[String],
+    lookups: &mut LookupCache,
+) -> Result<Vec<serde_json::Value>, String> {
+    if names.is_empty() {
+        return Ok(vec![]);
+    }
+
+    if !lookups.project_items.contains_key(resource) {
+        let url = jira_api::api_url(config, &format!("project/{}/{}", config.project_key, resource));
+        let items = get_json(client, config, &url, &[]).await?;
+        lookups
+            .project_items
+            .insert(resource.to_string(), items.as_array().cloned().unwrap_or_default());
+    }
+    let items = &lookups.project_items[resource];
+
+    names
+        .iter()
+        .map(|name| {
+            items
+                .iter()
+                .find(|item| item["name"].as_str().is_some_and(|n| normalize(n) == normalize(name)))
+                .and_then(|item| item["id"].as_str())
+                .map(|id| serde_json::json!({ "id": id }))
+                .ok_or_else(|| {
+                    let available: Vec<&str> = items.iter().filter_map(|item| item["name"].as_str()).collect();
+                    format!("{} '{}'을(를) 찾을 수 없습니다. 사용 가능한 값: {}", label, name, available.join(", "))
+                })
+        })
+        .collect()
+}
+
+// 생성 요청 fields에 표준 필드 추가
+pub async fn apply_to_fields(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    priority: &str,
+    options: &IssueFieldOptions,
+    lookups: &mut LookupCache,
+    fields: &mut serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    if let Some(priority_id) = resolve_priority(client, config, priority, lookups).await? {
+        fields.insert("priority".to_string(), serde_json::json!({ "id": priority_id }));
+    }
+
+    if let Some(assignee) = options.assignee.as_deref().filter(|a| !a.trim().is_empty()) {
+        let user_id = resolve_user_id(client, config, assignee, lookups).await?;
+        fields.insert("assignee".to_string(), jira_api::user_ref(config, Some(&user_id)));
+    }
+
+    if let Some(reporter) = options.reporter.as_deref().filter(|r| !r.trim().is_empty()) {
+        let user_id = resolve_user_id(client, config, reporter, lookups).await?;
+        fields.insert("reporter".to_string(), jira_api::user_ref(config, Some(&user_id)));
+    }
+
+    let components = resolve_project_items(client, config, "components", "컴포넌트", &options.components, lookups).await?;
+    if !components.is_empty() {
+        fields.insert("components".to_string(), serde_json::Value::Array(components));
+    }
+
+    let versions = resolve_project_items(client, config, "versions", "수정 버전", &options.fix_versions, lookups).await?;
+    if !versions.is_empty() {
+        fields.insert("fixVersions".to_string(), serde_json::Value::Array(versions));
+    }
+
+    if let Some(parent) = options.parent.as_deref().filter(|p| !p.trim().is_empty()) {
+        fields.insert("parent".to_string(), serde_json::json!({ "key": parent.trim() }));
+    }
+
+    if let Some(due_date) = options.due_date.as_deref().filter(|d| !d.trim().is_empty()) {
+        let date = chrono::NaiveDate::parse_from_str(due_date.trim(), "%Y-%m-%d")
+            .map_err(|e| format!("기한 날짜 형식 오류 ({}): {}", due_date, e))?;
+        fields.insert("duedate".to_string(), serde_json::json!(date.format("%Y-%m-%d").to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 요청하면 실패하는 주소로 설정해 캐시된 값만 사용하는지 확인
+    fn offline_config() -> JiraConfig {
+        serde_json::from_value(serde_json::json!({
+            "base_url": "http://127.0.0.1:9",
+            "email": "user@example.com",
+            "api_token": "token",
+            "project_key": "TEST"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn reuses_cached_lookups() {
+        let client = reqwest::Client::new();
+        let config = offline_config();
+        let mut lookups = LookupCache {
+            priorities: Some(vec![
+                serde_json::json!({ "id": "1", "name": "가장 높음" }),
+                serde_json::json!({ "id": "3", "name": "보통" }),
+            ]),
+            ..Default::default()
+        };
+        lookups.users.insert("홍길동".to_string(), "account-1".to_string());
+        lookups.project_items.insert(
+            "components".to_string(),
+            vec![serde_json::json!({ "id": "10", "name": "Backend" })],
+        );
+
+        assert_eq!(resolve_priority(&client, &config, "Critical", &mut lookups).await, Ok(Some("1".to_string())));
+        assert_eq!(resolve_priority(&client, &config, "Medium", &mut lookups).await, Ok(Some("3".to_string())));
+        assert_eq!(resolve_user_id(&client, &config, " 홍길동 ", &mut lookups).await, Ok("account-1".to_string()));
+
+        let components = resolve_project_items(&client, &config, "components", "컴포넌트", &["backend".to_string()], &mut lookups)
+            .await
+            .unwrap();
+        assert_eq!(components, vec![serde_json::json!({ "id": "10" })]);
+
+        // 캐시에 없는 조회는 실제 요청을 보냄
+        assert!(resolve_project_items(&client, &config, "versions", "수정 버전", &["1.0".to_string()], &mut lookups)
+            .await
+            .is_err());
+    }
+}
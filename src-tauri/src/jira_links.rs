@@ -0,0 +1,229 @@
+use serde::{Deserialize, Serialize};
+
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_fields;
+use crate::JiraConfig;
+
+// 이슈 연결 (차단, 관련, 중복 등)
+// 연결 유형은 서버마다 다르므로 issueLinkType 목록에서 이름/방향 문구로 찾습니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraLinkType {
+    pub id: String,
+    pub name: String,
+    // 예: "is blocked by"
+    pub inward: String,
+    // 예: "blocks"
+    pub outward: String,
+}
+
+// 기준 이슈 기준으로 "<기준 이슈> <link_type> <target>" 관계를 만듭니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLinkRequest {
+    // 연결 유형 이름 또는 방향 문구 (blocks, is blocked by, relates to, duplicates ...)
+    pub link_type: String,
+    pub target: String,
+}
+
+// 자주 쓰는 표현 → Jira 기본 연결 유형의 방향 문구
+const LINK_PHRASE_ALIASES: &[(&str, &[&str])] = &[
+    ("blocks", &["block", "차단", "차단함"]),
+    ("is blocked by", &["blocked by", "차단됨"]),
+    ("relates to", &["relates", "related", "relate", "관련", "관련됨"]),
+    ("duplicates", &["duplicate", "중복"]),
+    ("is duplicated by", &["duplicated by", "중복됨"]),
+    ("clones", &["clone", "복제"]),
+    ("is cloned by", &["cloned by", "복제됨"]),
+];
+
+fn normalize(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+pub async fn fetch_link_types(client: &reqwest::Client, config: &JiraConfig) -> Result<Vec<JiraLinkType>, String> {
+    let url = jira_api::api_url(config, "issueLinkType");
+    let response = client
+        .get(&url)
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+    serde_json::from_value(body["issueLinkTypes"].clone()).map_err(|e| format!("연결 유형 파싱 오류: {}", e))
+}
+
+// 연결 유형과 기준 이슈가 outward(능동) 쪽인지 반환
+fn resolve_link_type<'a>(types: &'a [JiraLinkType], wanted: &str) -> Result<(&'a JiraLinkType, bool), String> {
+    let wanted = normalize(wanted);
+    let phrase = LINK_PHRASE_ALIASES
+        .iter()
+        .find(|(phrase, aliases)| *phrase == wanted || aliases.contains(&wanted.as_str()))
+        .map(|(phrase, _)| phrase.to_string())
+        .unwrap_or_else(|| wanted.clone());
+
+    if let Some(found) = types.iter().find(|t| normalize(&t.outward) == phrase) {
+        return Ok((found, true));
+    }
+    if let Some(found) = types.iter().find(|t| normalize(&t.inward) == phrase) {
+        return Ok((found, false));
+    }
+    if let Some(found) = types.iter().find(|t| normalize(&t.name) == wanted || t.id == wanted) {
+        return Ok((found, true));
+    }
+
+    let available: Vec<String> = types
+        .iter()
+        .map(|t| format!("{} ({} / {})", t.name, t.outward, t.inward))
+        .collect();
+    Err(format!(
+        "연결 유형 '{}'을(를) 찾을 수 없습니다. 사용 가능한 유형: {}",
+        wanted,
+        available.join(", ")
+    ))
+}
+
+async fn create_link(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    link_type: &JiraLinkType,
+    inward_key: &str,
+    outward_key: &str,
+) -> Result<(), String> {
+    let url = jira_api::api_url(config, "issueLink");
+    // inwardIssue 쪽에서 보면 outward 문구가 적용됩니다 (inwardIssue blocks outwardIssue).
+    let body = serde_json::json!({
+        "type": { "id": link_type.id },
+        "inwardIssue": { "key": inward_key },
+        "outwardIssue": { "key": outward_key }
+    });
+
+    let response = client
+        .post(&url)
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+    Ok(())
+}
+
+// 모든 연결을 시도하고 실패한 연결의 오류 목록을 반환
+pub async fn try_link_issues(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    links: &[IssueLinkRequest],
+) -> Vec<String> {
+    if links.is_empty() {
+        return vec![];
+    }
+
+    let types = match fetch_link_types(client, config).await {
+        Ok(types) => types,
+        Err(e) => return vec![e],
+    };
+    let mut errors = Vec::new();
+
+    for link in links {
+        let target = link.target.trim();
+        let result = match resolve_link_type(&types, &link.link_type) {
+            Ok((link_type, true)) => create_link(client, config, link_type, issue_key, target).await,
+            Ok((link_type, false)) => create_link(client, config, link_type, target, issue_key).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => println!("이슈 연결 완료: {} {} {}", issue_key, link.link_type, target),
+            Err(e) => errors.push(format!("{} → {}: {}", link.link_type, target, e)),
+        }
+    }
+    errors
+}
+
+// 실패한 연결이 있으면 모아서 오류로 반환
+pub async fn link_issues(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    links: &[IssueLinkRequest],
+) -> Result<(), String> {
+    let errors = try_link_issues(client, config, issue_key, links).await;
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("이슈 연결 실패: {}", errors.join(" / ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Jira Cloud 기본 연결 유형 응답
+    fn link_types() -> Vec<JiraLinkType> {
+        let body = serde_json::json!({
+            "issueLinkTypes": [
+                { "id": "10000", "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                { "id": "10001", "name": "Cloners", "inward": "is cloned by", "outward": "clones" },
+                { "id": "10002", "name": "Duplicate", "inward": "is duplicated by", "outward": "duplicates" },
+                { "id": "10003", "name": "Relates", "inward": "relates to", "outward": "relates to" }
+            ]
+        });
+        serde_json::from_value(body["issueLinkTypes"].clone()).unwrap()
+    }
+
+    #[test]
+    fn resolves_link_type_phrases_and_direction() {
+        let types = link_types();
+        let cases = [
+            // 방향 문구
+            ("blocks", "10000", true),
+            ("Is Blocked By", "10000", false),
+            ("duplicates", "10002", true),
+            ("is duplicated by", "10002", false),
+            // 별칭
+            ("block", "10000", true),
+            ("차단", "10000", true),
+            ("blocked by", "10000", false),
+            ("차단됨", "10000", false),
+            ("관련", "10003", true),
+            ("related", "10003", true),
+            ("중복", "10002", true),
+            ("중복됨", "10002", false),
+            ("clone", "10001", true),
+            ("복제됨", "10001", false),
+            // 유형 이름 또는 id
+            ("Cloners", "10001", true),
+            ("10002", "10002", true),
+        ];
+        for (wanted, id, outward) in cases {
+            let (found, is_outward) = resolve_link_type(&types, wanted).unwrap();
+            assert_eq!((found.id.as_str(), is_outward), (id, outward), "입력: {}", wanted);
+        }
+    }
+
+    #[test]
+    fn reports_available_link_types() {
+        let error = resolve_link_type(&link_types(), "Causes").unwrap_err();
+        assert!(error.contains("'causes'"), "{}", error);
+        assert!(error.contains("Blocks (blocks / is blocked by)"), "{}", error);
+    }
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::jira_api::{self, JiraAuth};
+use crate::JiraConfig;
+
+// Jira 이슈 생성 메타데이터 (createmeta) 조회
+const PAGE_SIZE: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraIssueType {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+}
+
+// 분석 결과 타입 → 각 언어 로케일에서 쓰이는 Jira 기본 이슈 타입 이름
+const ISSUE_TYPE_ALIASES: &[(&str, &[&str])] = &[
+    ("bug", &["bug", "버그", "defect", "결함"]),
+    ("task", &["task", "작업", "태스크"]),
+    ("story", &["story", "스토리", "user story"]),
+    ("epic", &["epic", "에픽"]),
+    ("subtask", &["sub-task", "subtask", "하위 작업", "하위작업"]),
+];
+
+pub async fn fetch_issue_types(client: &reqwest::Client, config: &JiraConfig) -> Result<Vec<JiraIssueType>, String> {
+    let url = jira_api::api_url(config, &format!("issue/createmeta/{}/issuetypes", config.project_key));
+
+    let mut issue_types = Vec::new();
+    let mut start_at = 0;
+
+    loop {
+        let response = client
+            .get(&url)
+            .jira_auth(config)?
+            .header("Accept", "application/json")
+            .query(&[("startAt", start_at), ("maxResults", PAGE_SIZE)])
+            .send()
+            .await
+            .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("이슈 타입 메타데이터 조회 실패 ({}): {}", status, error_text));
+        }
+
+        let page: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+        // Server/Data Center는 목록을 values로 반환
+        let list = if page["issueTypes"].is_array() { &page["issueTypes"] } else { &page["values"] };
+        let items: Vec<JiraIssueType> = serde_json::from_value(list.clone())
+            .map_err(|e| format!("이슈 타입 파싱 오류: {}", e))?;
+        let fetched = items.len();
+        issue_types.extend(items);
+
+        let total = page["total"].as_u64().unwrap_or(0) as usize;
+        start_at += fetched;
+        if fetched == 0 || start_at >= total {
+            break;
+        }
+    }
+
+    Ok(issue_types)
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn find_by_id_or_name<'a>(types: &'a [JiraIssueType], value: &str) -> Option<&'a JiraIssueType> {
+    let value = normalize(value);
+    types
+        .iter()
+        .find(|t| t.id == value)
+        .or_else(|| types.iter().find(|t| normalize(&t.name) == value))
+}
+
+// 분석된 이슈 타입을 프로젝트의 실제 이슈 타입으로 변환
+// 우선순위: 사용자 매핑 → id/이름 일치 → 로케일별 기본 이름
+pub fn resolve_issue_type<'a>(
+    analyzed: &str,
+    types: &'a [JiraIssueType],
+    mapping: &HashMap<String, String>,
+) -> Result<&'a JiraIssueType, String> {
+    let analyzed_key = normalize(analyzed);
+
+    if let Some(target) = mapping
+        .iter()
+        .find(|(from, _)| normalize(from) == analyzed_key)
+        .map(|(_, to)| to)
+    {
+        return find_by_id_or_name(types, target).ok_or_else(|| {
+            format!(
+                "이슈 타입 매핑 '{}' → '{}'에 해당하는 타입이 프로젝트에 없습니다. 사용 가능한 타입: {}",
+                analyzed,
+                target,
+                describe_types(types)
+            )
+        });
+    }
+
+    if let Some(found) = find_by_id_or_name(types, analyzed) {
+        return Ok(found);
+    }
+
+    let aliases = ISSUE_TYPE_ALIASES
+        .iter()
+        .find(|(_, names)| names.contains(&analyzed_key.as_str()))
+        .map(|(_, names)| *names)
+        .unwrap_or(&[]);
+    if let Some(found) = types.iter().find(|t| aliases.contains(&normalize(&t.name).as_str())) {
+        return Ok(found);
+    }
+
+    Err(format!(
+        "이슈 타입 '{}'을(를) 프로젝트에서 찾을 수 없습니다. 사용 가능한 타입: {}",
+        analyzed,
+        describe_types(types)
+    ))
+}
+
+// 프로젝트의 하위 작업 이슈 타입
+pub fn find_subtask_type(types: &[JiraIssueType]) -> Result<&JiraIssueType, String> {
+    types.iter().find(|t| t.subtask).ok_or_else(|| {
+        format!(
+            "프로젝트에 하위 작업 이슈 타입이 없습니다. 사용 가능한 타입: {}",
+            describe_types(types)
+        )
+    })
+}
+
+fn describe_types(types: &[JiraIssueType]) -> String {
+    types
+        .iter()
+        .map(|t| format!("{} (id: {})", t.name, t.id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 한국어 로케일 프로젝트의 createmeta 응답
+    fn korean_types() -> Vec<JiraIssueType> {
+        let page = serde_json::json!({
+            "issueTypes": [
+                { "id": "10001", "name": "작업", "subtask": false },
+                { "id": "10002", "name": "버그", "subtask": false },
+                { "id": "10003", "name": "스토리", "subtask": false },
+                { "id": "10004", "name": "에픽", "subtask": false },
+                { "id": "10005", "name": "하위 작업", "subtask": true },
+                { "id": "10006", "name": "개선" }
+            ],
+            "total": 6
+        });
+        serde_json::from_value(page["issueTypes"].clone()).unwrap()
+    }
+
+    #[test]
+    fn resolves_issue_type_in_order() {
+        let types = korean_types();
+        let mapping = HashMap::from([
+            ("Task".to_string(), "개선".to_string()),
+            ("Story".to_string(), "10004".to_string()),
+        ]);
+
+        let cases = [
+            // 사용자 매핑이 가장 먼저 적용
+            ("Task", "10006"),
+            ("story", "10004"),
+            // id/이름 일치
+            ("10002", "10002"),
+            ("버그", "10002"),
+            ("  하위 작업 ", "10005"),
+            // 로케일별 기본 이름
+            ("Bug", "10002"),
+            ("Defect", "10002"),
+            ("Epic", "10004"),
+            ("Sub-task", "10005"),
+        ];
+        for (analyzed, expected) in cases {
+            let found = resolve_issue_type(analyzed, &types, &mapping).unwrap();
+            assert_eq!(found.id, expected, "입력: {}", analyzed);
+        }
+
+        // 매핑 대상이 없으면 별칭으로 넘어가지 않고 오류
+        let broken = HashMap::from([("Bug".to_string(), "결함 보고".to_string())]);
+        let error = resolve_issue_type("Bug", &types, &broken).unwrap_err();
+        assert!(error.contains("결함 보고"));
+        assert!(error.contains("버그 (id: 10002)"));
+
+        let error = resolve_issue_type("Incident", &types, &HashMap::new()).unwrap_err();
+        assert!(error.contains("Incident"));
+    }
+
+    #[test]
+    fn finds_subtask_type() {
+        let types = korean_types();
+        assert_eq!(find_subtask_type(&types).unwrap().name, "하위 작업");
+
+        let without: Vec<_> = types.into_iter().filter(|t| !t.subtask).collect();
+        assert!(find_subtask_type(&without).unwrap_err().contains("하위 작업 이슈 타입이 없습니다"));
+    }
+}
@@ -0,0 +1,745 @@
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+
+use crate::jira_api::JiraAuthMode;
+use crate::JiraConfig;
+
+// Jira Cloud OAuth 2.0 (3LO) 로그인
+// 인가 코드 + PKCE 흐름으로 브라우저에서 로그인하고 루프백 주소로 인가 코드를 받습니다.
+// 발급받은 토큰으로 접근 가능한 사이트(cloud id)를 찾고, API 요청은 api.atlassian.com/ex/jira/{cloudid}로 보냅니다.
+const TOKEN_DIR: &str = "oauth";
+const TOKEN_FILE: &str = "jira_token.json";
+const CALLBACK_PATH: &str = "/callback";
+const LOGIN_TIMEOUT_SECS: u64 = 300;
+// 만료 직전 토큰으로 요청하지 않도록 여유를 두고 갱신
+const REFRESH_MARGIN_SECS: i64 = 60;
+const DEFAULT_EXPIRES_IN: i64 = 3600;
+const PKCE_CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+
+fn default_redirect_port() -> u16 {
+    8765
+}
+
+fn default_scopes() -> String {
+    "read:jira-work write:jira-work read:jira-user offline_access".to_string()
+}
+
+fn default_auth_url() -> String {
+    "https://auth.atlassian.com/authorize".to_string()
+}
+
+fn default_token_url() -> String {
+    "https://auth.atlassian.com/oauth/token".to_string()
+}
+
+fn default_api_base() -> String {
+    "https://api.atlassian.com".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraOAuthConfig {
+    pub client_id: String,
+    // 기밀 클라이언트로 등록된 앱이면 필요 (PKCE만 사용하는 앱은 비워둠)
+    #[serde(default)]
+    pub client_secret: String,
+    // 개발자 콘솔에 등록한 콜백 주소 http://127.0.0.1:{포트}/callback 의 포트
+    #[serde(default = "default_redirect_port")]
+    pub redirect_port: u16,
+    #[serde(default = "default_scopes")]
+    pub scopes: String,
+    // 인증/API 서버 주소 (테스트 시 모의 서버로 바꿀 수 있음)
+    #[serde(default = "default_auth_url")]
+    pub auth_url: String,
+    #[serde(default = "default_token_url")]
+    pub token_url: String,
+    #[serde(default = "default_api_base")]
+    pub api_base: String,
+}
+
+// 요청에 사용할 액세스 토큰과 API 주소 (설정에는 저장하지 않고 명령 실행 시 채움)
+#[derive(Debug, Clone)]
+pub struct OAuthSession {
+    pub access_token: String,
+    // {api_base}/ex/jira/{cloudid}
+    pub api_base_url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JiraOAuthStatus {
+    pub site_url: String,
+    pub cloud_id: String,
+    pub expires_at: DateTime<Utc>,
+    pub scope: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredToken {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    expires_at: DateTime<Utc>,
+    cloud_id: String,
+    site_url: String,
+    #[serde(default)]
+    scope: String,
+}
+
+impl StoredToken {
+    fn status(&self) -> JiraOAuthStatus {
+        JiraOAuthStatus {
+            site_url: self.site_url.clone(),
+            cloud_id: self.cloud_id.clone(),
+            expires_at: self.expires_at,
+            scope: self.scope.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    expires_in: i64,
+    #[serde(default)]
+    scope: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AccessibleResource {
+    id: String,
+    url: String,
+    #[serde(default)]
+    name: String,
+}
+
+fn token_path(dir: &Path) -> PathBuf {
+    dir.join(TOKEN_FILE)
+}
+
+fn load_token(dir: &Path) -> Result<Option<StoredToken>, String> {
+    let path = token_path(dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("OAuth 토큰 파일 읽기 실패: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("OAuth 토큰 파일 파싱 실패: {}", e))
+}
+
+fn save_token(dir: &Path, token: &StoredToken) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("OAuth 토큰 디렉토리 생성 실패: {}", e))?;
+    let json = serde_json::to_string_pretty(token).map_err(|e| format!("OAuth 토큰 직렬화 실패: {}", e))?;
+
+    // 갱신 토큰이 들어 있으므로 소유자만 읽을 수 있게 저장 (기존 파일도 권한을 다시 맞춤)
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(token_path(dir))
+        .map_err(|e| format!("OAuth 토큰 저장 실패: {}", e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))
+            .map_err(|e| format!("OAuth 토큰 파일 권한 설정 실패: {}", e))?;
+    }
+    std::io::Write::write_all(&mut file, json.as_bytes()).map_err(|e| format!("OAuth 토큰 저장 실패: {}", e))
+}
+
+fn refresh_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+fn oauth_config(config: &JiraConfig) -> Result<&JiraOAuthConfig, String> {
+    config
+        .oauth
+        .as_ref()
+        .filter(|o| !o.client_id.trim().is_empty())
+        .ok_or_else(|| "OAuth 클라이언트 ID를 설정해주세요.".to_string())
+}
+
+fn random_string(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|_| PKCE_CHARSET[rng.gen_range(0..PKCE_CHARSET.len())] as char)
+        .collect()
+}
+
+// PKCE S256: BASE64URL(SHA256(code_verifier))
+fn code_challenge(verifier: &str) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+fn expires_at(expires_in: i64) -> DateTime<Utc> {
+    let seconds = if expires_in > 0 { expires_in } else { DEFAULT_EXPIRES_IN };
+    Utc::now() + chrono::Duration::seconds(seconds)
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let body = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>PenguExec</title></head><body><p>{}</p></body></html>",
+        message
+    );
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+// 루프백 콜백에서 인가 코드를 받음 (콜백 경로가 아닌 요청은 무시)
+async fn wait_for_callback(listener: TcpListener, expected_state: &str) -> Result<String, String> {
+    loop {
+        let (mut stream, _) = listener
+            .accept()
+            .await
+            .map_err(|e| format!("로그인 콜백 수신 오류: {}", e))?;
+
+        let mut buffer = vec![0u8; 8192];
+        let read = match stream.read(&mut buffer).await {
+            Ok(read) => read,
+            Err(e) => {
+                println!("로그인 콜백 읽기 실패: {}", e);
+                continue;
+            }
+        };
+        let request = String::from_utf8_lossy(&buffer[..read]);
+        let target = request
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .unwrap_or("")
+            .to_string();
+
+        if target.split('?').next() != Some(CALLBACK_PATH) {
+            respond(&mut stream, "404 Not Found", "Not Found").await;
+            continue;
+        }
+
+        let params: HashMap<String, String> = reqwest::Url::parse(&format!("http://127.0.0.1{}", target))
+            .map(|url| url.query_pairs().into_owned().collect())
+            .unwrap_or_default();
+
+        let result = if let Some(error) = params.get("error") {
+            Err(format!(
+                "Jira 로그인이 거부되었습니다: {}",
+                params.get("error_description").unwrap_or(error)
+            ))
+        } else if params.get("state").map(|s| s.as_str()) != Some(expected_state) {
+            Err("로그인 응답의 state 값이 일치하지 않습니다. 다시 로그인해주세요.".to_string())
+        } else {
+            params
+                .get("code")
+                .cloned()
+                .ok_or_else(|| "로그인 응답에 인가 코드가 없습니다.".to_string())
+        };
+
+        let message = if result.is_ok() {
+            "로그인이 완료되었습니다. 이 창을 닫고 PenguExec으로 돌아가세요."
+        } else {
+            "로그인에 실패했습니다. PenguExec에서 오류 내용을 확인해주세요."
+        };
+        respond(&mut stream, "200 OK", message).await;
+        return result;
+    }
+}
+
+async fn request_token(
+    client: &reqwest::Client,
+    oauth: &JiraOAuthConfig,
+    mut body: serde_json::Value,
+) -> Result<TokenResponse, String> {
+    body["client_id"] = serde_json::json!(oauth.client_id.trim());
+    if !oauth.client_secret.is_empty() {
+        body["client_secret"] = serde_json::json!(oauth.client_secret);
+    }
+
+    let response = client
+        .post(&oauth.token_url)
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        println!("OAuth 토큰 요청 오류 ({}): {}", status, error_text);
+        return Err(format!("OAuth 토큰 발급 실패 ({}): {}", status, error_text));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("토큰 응답 파싱 오류: {}", e))
+}
+
+// 토큰으로 접근 가능한 사이트 중 설정한 Jira URL과 일치하는 사이트를 선택
+async fn select_site(
+    client: &reqwest::Client,
+    oauth: &JiraOAuthConfig,
+    access_token: &str,
+    base_url: &str,
+) -> Result<AccessibleResource, String> {
+    let url = format!("{}/oauth/token/accessible-resources", oauth.api_base.trim_end_matches('/'));
+    let response = client
+        .get(&url)
+        .bearer_auth(access_token)
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(format!("접근 가능한 Jira 사이트 조회 실패 ({}): {}", status, error_text));
+    }
+
+    let mut sites: Vec<AccessibleResource> = response
+        .json()
+        .await
+        .map_err(|e| format!("사이트 목록 파싱 오류: {}", e))?;
+
+    let wanted = base_url.trim().trim_end_matches('/').to_lowercase();
+    if !wanted.is_empty() {
+        if let Some(index) = sites.iter().position(|s| s.url.trim_end_matches('/').to_lowercase() == wanted) {
+            return Ok(sites.swap_remove(index));
+        }
+    }
+
+    match sites.len() {
+        0 => Err("이 계정으로 접근할 수 있는 Jira 사이트가 없습니다.".to_string()),
+        1 if wanted.is_empty() => Ok(sites.remove(0)),
+        _ => Err(format!(
+            "설정한 Jira URL과 일치하는 사이트가 없습니다. 다음 중 하나로 설정해주세요: {}",
+            sites
+                .iter()
+                .map(|s| format!("{} ({})", s.url, s.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+// 브라우저 로그인 후 토큰을 발급받아 저장
+pub async fn login(client: &reqwest::Client, config: &JiraConfig) -> Result<JiraOAuthStatus, String> {
+    let oauth = oauth_config(config)?;
+
+    let listener = TcpListener::bind(("127.0.0.1", oauth.redirect_port))
+        .await
+        .map_err(|e| format!("로그인 콜백 포트 {} 열기 실패: {}", oauth.redirect_port, e))?;
+    let redirect_uri = format!("http://127.0.0.1:{}{}", oauth.redirect_port, CALLBACK_PATH);
+
+    let verifier = random_string(64);
+    let state = random_string(32);
+    let challenge = code_challenge(&verifier);
+    let authorize_url = reqwest::Url::parse_with_params(
+        &oauth.auth_url,
+        &[
+            ("audience", "api.atlassian.com"),
+            ("client_id", oauth.client_id.trim()),
+            ("scope", oauth.scopes.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("state", state.as_str()),
+            ("response_type", "code"),
+            ("prompt", "consent"),
+            ("code_challenge", challenge.as_str()),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map_err(|e| format!("잘못된 인증 서버 주소입니다: {}", e))?;
+
+    println!("Jira 로그인 페이지: {}", authorize_url);
+    if let Err(e) = tauri_plugin_opener::open_url(authorize_url.as_str(), None::<&str>) {
+        println!("브라우저 열기 실패, 위 주소를 직접 열어주세요: {}", e);
+    }
+
+    let code = tokio::time::timeout(Duration::from_secs(LOGIN_TIMEOUT_SECS), wait_for_callback(listener, &state))
+        .await
+        .map_err(|_| format!("로그인이 {}초 안에 완료되지 않았습니다.", LOGIN_TIMEOUT_SECS))??;
+
+    let token = request_token(
+        client,
+        oauth,
+        serde_json::json!({
+            "grant_type": "authorization_code",
+            "code": code,
+            "redirect_uri": redirect_uri,
+            "code_verifier": verifier
+        }),
+    )
+    .await?;
+
+    let site = select_site(client, oauth, &token.access_token, &config.base_url).await?;
+    let stored = StoredToken {
+        access_token: token.access_token,
+        refresh_token: token.refresh_token,
+        expires_at: expires_at(token.expires_in),
+        cloud_id: site.id,
+        site_url: site.url,
+        scope: token.scope,
+    };
+
+    let _guard = refresh_lock().lock().await;
+    save_token(Path::new(TOKEN_DIR), &stored)?;
+    println!("Jira OAuth 로그인 완료: {} ({})", stored.site_url, stored.cloud_id);
+    Ok(stored.status())
+}
+
+pub fn status() -> Result<Option<JiraOAuthStatus>, String> {
+    Ok(load_token(Path::new(TOKEN_DIR))?.map(|token| token.status()))
+}
+
+pub async fn logout() -> Result<(), String> {
+    let _guard = refresh_lock().lock().await;
+    let path = token_path(Path::new(TOKEN_DIR));
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("OAuth 토큰 삭제 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+async fn refresh(
+    client: &reqwest::Client,
+    oauth: &JiraOAuthConfig,
+    dir: &Path,
+    token: StoredToken,
+) -> Result<StoredToken, String> {
+    let refresh_token = token
+        .refresh_token
+        .clone()
+        .ok_or_else(|| "Jira 로그인이 만료되었습니다. 다시 로그인해주세요.".to_string())?;
+
+    let response = request_token(
+        client,
+        oauth,
+        serde_json::json!({
+            "grant_type": "refresh_token",
+            "refresh_token": refresh_token
+        }),
+    )
+    .await
+    .map_err(|e| format!("{} 다시 로그인해주세요.", e))?;
+
+    // 갱신 토큰은 교체 발급되므로 새 값이 오면 바꿔서 저장
+    let refreshed = StoredToken {
+        access_token: response.access_token,
+        refresh_token: response.refresh_token.or(Some(refresh_token)),
+        expires_at: expires_at(response.expires_in),
+        scope: if response.scope.is_empty() { token.scope } else { response.scope },
+        ..token
+    };
+    save_token(dir, &refreshed)?;
+    println!("Jira OAuth 토큰 갱신 완료");
+    Ok(refreshed)
+}
+
+// OAuth 모드이면 저장된 토큰(만료 임박 시 갱신)으로 요청에 사용할 세션을 채움
+pub async fn authorize(client: &reqwest::Client, config: JiraConfig) -> Result<JiraConfig, String> {
+    authorize_from(client, config, Path::new(TOKEN_DIR)).await
+}
+
+// 토큰 저장 위치를 받아 처리 (테스트에서는 임시 디렉토리 사용)
+async fn authorize_from(client: &reqwest::Client, mut config: JiraConfig, dir: &Path) -> Result<JiraConfig, String> {
+    if config.auth_mode != JiraAuthMode::CloudOauth {
+        return Ok(config);
+    }
+    let oauth = oauth_config(&config)?;
+
+    // 같은 갱신 토큰으로 동시에 갱신하지 않도록 직렬화
+    let _guard = refresh_lock().lock().await;
+    let mut token = load_token(dir)?.ok_or_else(|| "Jira OAuth 로그인이 필요합니다.".to_string())?;
+    if token.expires_at - chrono::Duration::seconds(REFRESH_MARGIN_SECS) <= Utc::now() {
+        token = refresh(client, oauth, dir, token).await?;
+    }
+
+    let session = OAuthSession {
+        access_token: token.access_token,
+        api_base_url: format!("{}/ex/jira/{}", oauth.api_base.trim_end_matches('/'), token.cloud_id),
+    };
+    // 이슈 링크(browse) 등은 사이트 주소를 사용
+    if config.base_url.trim().is_empty() {
+        config.base_url = token.site_url;
+    }
+    config.oauth_session = Some(session);
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira_api::JiraAuth;
+    use std::sync::{Arc, Mutex as StdMutex};
+
+    // 경로별로 고정 JSON을 돌려주는 모의 서버 (받은 요청은 "경로 본문" 형태로 기록)
+    async fn mock_server(routes: Vec<(&'static str, String)>) -> (String, Arc<StdMutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(StdMutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut data = Vec::new();
+                let mut buffer = [0u8; 4096];
+                let header_end = loop {
+                    let read = stream.read(&mut buffer).await.unwrap_or(0);
+                    if read == 0 {
+                        break None;
+                    }
+                    data.extend_from_slice(&buffer[..read]);
+                    if let Some(end) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+                        break Some(end + 4);
+                    }
+                };
+                let Some(header_end) = header_end else {
+                    continue;
+                };
+                let head = String::from_utf8_lossy(&data[..header_end]).to_string();
+                let content_length = head
+                    .lines()
+                    .filter_map(|line| line.split_once(':'))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                    .unwrap_or(0);
+                while data.len() < header_end + content_length {
+                    let read = stream.read(&mut buffer).await.unwrap_or(0);
+                    if read == 0 {
+                        break;
+                    }
+                    data.extend_from_slice(&buffer[..read]);
+                }
+
+                let path = head.split_whitespace().nth(1).unwrap_or("").to_string();
+                let body = String::from_utf8_lossy(&data[header_end..]).to_string();
+                recorded.lock().unwrap().push(format!("{} {}", path, body));
+
+                let (status, json) = match routes.iter().find(|(route, _)| path.starts_with(route)) {
+                    Some((_, json)) => ("200 OK", json.clone()),
+                    None => ("404 Not Found", "{}".to_string()),
+                };
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    json.len(),
+                    json
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn oauth_config_for(base_url: &str) -> JiraOAuthConfig {
+        JiraOAuthConfig {
+            client_id: "client".to_string(),
+            client_secret: String::new(),
+            redirect_port: default_redirect_port(),
+            scopes: default_scopes(),
+            auth_url: format!("{}/authorize", base_url),
+            token_url: format!("{}/oauth/token", base_url),
+            api_base: base_url.to_string(),
+        }
+    }
+
+    fn oauth_jira_config(base_url: &str) -> JiraConfig {
+        let mut config: JiraConfig = serde_json::from_value(serde_json::json!({
+            "base_url": "",
+            "email": "",
+            "api_token": "",
+            "project_key": "TEST",
+            "auth_mode": "cloud_oauth"
+        }))
+        .unwrap();
+        config.oauth = Some(oauth_config_for(base_url));
+        config
+    }
+
+    fn temp_token_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pengu_oauth_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn stored_token(expires_at: DateTime<Utc>) -> StoredToken {
+        StoredToken {
+            access_token: "old-access".to_string(),
+            refresh_token: Some("old-refresh".to_string()),
+            expires_at,
+            cloud_id: "cloud-1".to_string(),
+            site_url: "https://team.atlassian.net".to_string(),
+            scope: "read:jira-work".to_string(),
+        }
+    }
+
+    // RFC 7636 부록 B 예시 값
+    #[test]
+    fn code_challenge_matches_rfc_example() {
+        assert_eq!(
+            code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+        let verifier = random_string(64);
+        assert_eq!(verifier.len(), 64);
+        assert!(verifier.bytes().all(|b| PKCE_CHARSET.contains(&b)));
+    }
+
+    #[tokio::test]
+    async fn callback_returns_code_after_ignoring_other_paths() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let waiting = tokio::spawn(async move { wait_for_callback(listener, "expected").await });
+
+        let client = reqwest::Client::new();
+        let other = client.get(format!("{}/favicon.ico", base_url)).send().await.unwrap();
+        assert_eq!(other.status(), reqwest::StatusCode::NOT_FOUND);
+        client
+            .get(format!("{}{}?code=auth-code&state=expected", base_url, CALLBACK_PATH))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(waiting.await.unwrap(), Ok("auth-code".to_string()));
+    }
+
+    #[tokio::test]
+    async fn callback_rejects_state_mismatch() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let waiting = tokio::spawn(async move { wait_for_callback(listener, "expected").await });
+
+        reqwest::Client::new()
+            .get(format!("{}{}?code=auth-code&state=forged", base_url, CALLBACK_PATH))
+            .send()
+            .await
+            .unwrap();
+
+        let error = waiting.await.unwrap().unwrap_err();
+        assert!(error.contains("state"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn select_site_matches_configured_url() {
+        let sites = serde_json::json!([
+            { "id": "a", "url": "https://alpha.atlassian.net", "name": "alpha" },
+            { "id": "b", "url": "https://beta.atlassian.net", "name": "beta" }
+        ]);
+        let (base_url, _) = mock_server(vec![("/oauth/token/accessible-resources", sites.to_string())]).await;
+        let oauth = oauth_config_for(&base_url);
+        let client = reqwest::Client::new();
+
+        let site = select_site(&client, &oauth, "token", "https://Beta.atlassian.net/").await.unwrap();
+        assert_eq!(site.id, "b");
+
+        let error = select_site(&client, &oauth, "token", "https://gamma.atlassian.net").await.unwrap_err();
+        assert!(error.contains("https://alpha.atlassian.net"), "{}", error);
+
+        // URL을 비워두면 사이트가 하나일 때만 자동 선택
+        assert!(select_site(&client, &oauth, "token", "").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn select_site_uses_only_site_when_url_is_empty() {
+        let sites = serde_json::json!([{ "id": "only", "url": "https://only.atlassian.net" }]);
+        let (base_url, _) = mock_server(vec![("/oauth/token/accessible-resources", sites.to_string())]).await;
+        let site = select_site(&reqwest::Client::new(), &oauth_config_for(&base_url), "token", "")
+            .await
+            .unwrap();
+        assert_eq!(site.id, "only");
+    }
+
+    #[tokio::test]
+    async fn authorize_refreshes_expired_token() {
+        let token_response = serde_json::json!({ "access_token": "new-access", "expires_in": 3600 });
+        let (base_url, requests) = mock_server(vec![("/oauth/token", token_response.to_string())]).await;
+        let dir = temp_token_dir("refresh");
+        save_token(&dir, &stored_token(Utc::now() - chrono::Duration::seconds(10))).unwrap();
+
+        let config = authorize_from(&reqwest::Client::new(), oauth_jira_config(&base_url), &dir)
+            .await
+            .unwrap();
+
+        let session = config.oauth_session.unwrap();
+        assert_eq!(session.access_token, "new-access");
+        assert_eq!(session.api_base_url, format!("{}/ex/jira/cloud-1", base_url));
+        assert_eq!(config.base_url, "https://team.atlassian.net");
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].contains("\"grant_type\":\"refresh_token\""), "{}", requests[0]);
+        assert!(requests[0].contains("\"refresh_token\":\"old-refresh\""), "{}", requests[0]);
+
+        // 새 갱신 토큰이 오지 않으면 기존 갱신 토큰을 유지
+        let saved = load_token(&dir).unwrap().unwrap();
+        assert_eq!(saved.access_token, "new-access");
+        assert_eq!(saved.refresh_token.as_deref(), Some("old-refresh"));
+        assert!(saved.expires_at > Utc::now());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(token_path(&dir)).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn authorize_keeps_valid_token() {
+        let (base_url, requests) = mock_server(vec![]).await;
+        let dir = temp_token_dir("valid");
+        save_token(&dir, &stored_token(Utc::now() + chrono::Duration::seconds(3600))).unwrap();
+
+        let config = authorize_from(&reqwest::Client::new(), oauth_jira_config(&base_url), &dir)
+            .await
+            .unwrap();
+
+        assert_eq!(config.oauth_session.unwrap().access_token, "old-access");
+        assert!(requests.lock().unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn authorize_requires_login() {
+        let dir = temp_token_dir("missing");
+        let error = authorize_from(&reqwest::Client::new(), oauth_jira_config("http://127.0.0.1:9"), &dir)
+            .await
+            .unwrap_err();
+        assert!(error.contains("로그인"), "{}", error);
+    }
+
+    #[test]
+    fn oauth_request_without_session_is_error() {
+        let config = oauth_jira_config("http://127.0.0.1:9");
+        let request = reqwest::Client::new().get("http://127.0.0.1:9/rest/api/3/myself");
+        assert!(request.jira_auth(&config).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_fields;
+use crate::{parse_jira_issue, JiraConfig, JiraIssue, ISSUE_BASE_FIELDS};
+
+// JQL 검색
+// Cloud의 새 검색 API(/search/jql)는 startAt 대신 nextPageToken으로 페이지를 넘기고 total을 돌려주지 않으므로
+// 전체 개수는 approximate-count API로 따로 조회합니다.
+// Server/Data Center는 기존 /search API를 사용하며 startAt 오프셋을 페이지 토큰으로 씁니다.
+const PAGE_SIZE: usize = 100;
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JiraSearchResult {
+    pub issues: Vec<JiraIssue>,
+    // JQL에 해당하는 전체 이슈 수 (근사값, 조회 실패 시 None)
+    pub total: Option<u64>,
+    // 한도에 도달해 남은 결과가 있으면 다음 호출에 넘길 토큰
+    pub next_page_token: Option<String>,
+}
+
+async fn post_json(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    url: &str,
+    body: &serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let response = client
+        .post(url)
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))
+}
+
+async fn approximate_count(client: &reqwest::Client, config: &JiraConfig, jql: &str) -> Option<u64> {
+    let url = jira_api::api_url(config, "search/approximate-count");
+    match post_json(client, config, &url, &serde_json::json!({ "jql": jql })).await {
+        Ok(body) => body["count"].as_u64(),
+        Err(e) => {
+            println!("검색 결과 개수 조회 실패: {}", e);
+            None
+        }
+    }
+}
+
+pub async fn search_issues(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    jql: &str,
+    extra_fields: &[String],
+    page_token: Option<String>,
+    limit: Option<usize>,
+) -> Result<JiraSearchResult, String> {
+    if jql.trim().is_empty() {
+        return Err("JQL을 입력해주세요.".to_string());
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let mut fields: Vec<String> = ISSUE_BASE_FIELDS.iter().map(|f| f.to_string()).collect();
+    for field in extra_fields {
+        if !fields.contains(field) {
+            fields.push(field.clone());
+        }
+    }
+
+    let server = jira_api::is_server(config);
+    let url = jira_api::api_url(config, jira_api::search_path(config));
+    let mut issues = Vec::new();
+    let mut next_page_token = page_token;
+    let mut total = None;
+
+    loop {
+        let mut body = serde_json::json!({
+            "jql": jql,
+            "fields": fields,
+            "maxResults": PAGE_SIZE.min(limit - issues.len())
+        });
+        if let Some(token) = &next_page_token {
+            if server {
+                let start_at: u64 = token.parse().map_err(|_| format!("잘못된 페이지 토큰입니다: {}", token))?;
+                body["startAt"] = serde_json::json!(start_at);
+            } else {
+                body["nextPageToken"] = serde_json::json!(token);
+            }
+        }
+
+        let page = post_json(client, config, &url, &body).await?;
+        let page_issues = page["issues"].as_array().cloned().unwrap_or_default();
+        issues.extend(page_issues.iter().map(|issue| parse_jira_issue(issue, extra_fields)));
+
+        if server {
+            let page_total = page["total"].as_u64().unwrap_or(0);
+            let next_start = page["startAt"].as_u64().unwrap_or(0) + page_issues.len() as u64;
+            total = Some(page_total);
+            next_page_token = (next_start < page_total).then(|| next_start.to_string());
+        } else {
+            let is_last = page["isLast"].as_bool().unwrap_or(true);
+            next_page_token = page["nextPageToken"].as_str().map(|t| t.to_string()).filter(|_| !is_last);
+        }
+        if next_page_token.is_none() || page_issues.is_empty() || issues.len() >= limit {
+            break;
+        }
+    }
+
+    println!("JQL 검색 완료: {}건 ({})", issues.len(), jql);
+
+    Ok(JiraSearchResult {
+        issues,
+        total: if server { total } else { approximate_count(client, config, jql).await },
+        next_page_token,
+    })
+}
@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_custom_fields::{self, FieldDescriptor};
+use crate::jira_fields::{self, LookupCache};
+use crate::JiraConfig;
+
+// 이슈 워크플로 전환 (상태 변경)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraTransition {
+    pub id: String,
+    pub name: String,
+    pub to_status: String,
+    // new / indeterminate / done
+    pub to_status_category: String,
+    pub has_screen: bool,
+    // 전환 화면에서 입력할 수 있는 필드 (해결 상태, 커스텀 필드 등)
+    pub fields: Vec<FieldDescriptor>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TransitionRequest {
+    // 전환 id, 전환 이름 또는 대상 상태 이름
+    pub transition: String,
+    #[serde(default)]
+    pub resolution: Option<String>,
+    // Markdown (API 버전에 맞게 변환하여 댓글로 추가)
+    #[serde(default)]
+    pub comment: Option<String>,
+    // 전환 화면 필드 id 또는 이름 → 값
+    #[serde(default)]
+    pub fields: HashMap<String, serde_json::Value>,
+}
+
+fn transitions_url(config: &JiraConfig, issue_key: &str) -> String {
+    jira_api::api_url(config, &format!("issue/{}/transitions", issue_key))
+}
+
+fn parse_transition(transition: &serde_json::Value) -> Option<JiraTransition> {
+    let to = &transition["to"];
+    Some(JiraTransition {
+        id: transition["id"].as_str()?.to_string(),
+        name: transition["name"].as_str().unwrap_or("").to_string(),
+        to_status: to["name"].as_str().unwrap_or("").to_string(),
+        to_status_category: to["statusCategory"]["key"].as_str().unwrap_or("").to_string(),
+        has_screen: transition["hasScreen"].as_bool().unwrap_or(false),
+        fields: jira_custom_fields::parse_field_map(&transition["fields"]),
+    })
+}
+
+pub async fn fetch_transitions(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+) -> Result<Vec<JiraTransition>, String> {
+    let response = client
+        .get(transitions_url(config, issue_key))
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .query(&[("expand", "transitions.fields")])
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+
+    Ok(body["transitions"]
+        .as_array()
+        .map(|list| list.iter().filter_map(parse_transition).collect())
+        .unwrap_or_default())
+}
+
+// id → 전환 이름 → 대상 상태 이름 순으로 찾습니다.
+fn find_transition<'a>(transitions: &'a [JiraTransition], wanted: &str) -> Result<&'a JiraTransition, String> {
+    let key = wanted.trim().to_lowercase();
+    transitions
+        .iter()
+        .find(|t| t.id == key)
+        .or_else(|| transitions.iter().find(|t| t.name.to_lowercase() == key))
+        .or_else(|| transitions.iter().find(|t| t.to_status.to_lowercase() == key))
+        .ok_or_else(|| {
+            let available: Vec<String> = transitions
+                .iter()
+                .map(|t| format!("{} → {} (id: {})", t.name, t.to_status, t.id))
+                .collect();
+            format!(
+                "전환 '{}'을(를) 사용할 수 없습니다. 가능한 전환: {}",
+                wanted,
+                if available.is_empty() { "없음".to_string() } else { available.join(", ") }
+            )
+        })
+}
+
+fn resolve_resolution(transition: &JiraTransition, resolution: &str) -> Result<serde_json::Value, String> {
+    let Some(descriptor) = transition.fields.iter().find(|f| f.id == "resolution") else {
+        return Err(format!("'{}' 전환에서는 해결 상태를 지정할 수 없습니다.", transition.name));
+    };
+    descriptor
+        .allowed_values
+        .iter()
+        .find(|v| v.id == resolution.trim() || v.value.eq_ignore_ascii_case(resolution.trim()))
+        .map(|v| serde_json::json!({ "id": v.id }))
+        .ok_or_else(|| {
+            let allowed: Vec<&str> = descriptor.allowed_values.iter().map(|v| v.value.as_str()).collect();
+            format!("해결 상태 '{}'을(를) 찾을 수 없습니다. 허용 값: {}", resolution, allowed.join(", "))
+        })
+}
+
+pub async fn transition_issue(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    request: &TransitionRequest,
+) -> Result<(), String> {
+    let transitions = fetch_transitions(client, config, issue_key).await?;
+    let transition = find_transition(&transitions, &request.transition)?;
+
+    let mut fields = serde_json::Map::new();
+    if let Some(resolution) = request.resolution.as_deref().filter(|r| !r.trim().is_empty()) {
+        fields.insert("resolution".to_string(), resolve_resolution(transition, resolution)?);
+    }
+    if !request.fields.is_empty() {
+        jira_custom_fields::apply_edits(
+            client,
+            config,
+            &transition.fields,
+            &request.fields,
+            &mut LookupCache::default(),
+            &mut fields,
+        )
+        .await?;
+    }
+
+    // 기본값이 없는 필수 화면 필드가 비어 있으면 요청 전에 알려줍니다.
+    let missing: Vec<String> = transition
+        .fields
+        .iter()
+        .filter(|f| f.required && !f.has_default_value && !fields.contains_key(&f.id))
+        .map(|f| format!("{} ({})", f.name, f.id))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "'{}' 전환에 필요한 필드가 비어 있습니다: {}",
+            transition.name,
+            missing.join(", ")
+        ));
+    }
+
+    let mut body = serde_json::json!({ "transition": { "id": transition.id } });
+    if !fields.is_empty() {
+        body["fields"] = serde_json::Value::Object(fields);
+    }
+    if let Some(comment) = request.comment.as_deref().filter(|c| !c.trim().is_empty()) {
+        body["update"] = serde_json::json!({
+            "comment": [{ "add": { "body": jira_api::rich_text(config, comment) } }]
+        });
+    }
+
+    let response = client
+        .post(transitions_url(config, issue_key))
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        println!("Jira 전환 오류 ({}): {}", status, error_text);
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    println!("Jira 이슈 전환 완료: {} → {}", issue_key, transition.to_status);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn transitions() -> Vec<JiraTransition> {
+        let body = json!({
+            "transitions": [
+                {
+                    "id": "11",
+                    "name": "진행 시작",
+                    "to": { "name": "In Progress", "statusCategory": { "key": "indeterminate" } }
+                },
+                {
+                    "id": "31",
+                    "name": "Resolve",
+                    "hasScreen": true,
+                    "to": { "name": "완료", "statusCategory": { "key": "done" } },
+                    "fields": {
+                        "resolution": {
+                            "required": true,
+                            "name": "Resolution",
+                            "schema": { "type": "resolution", "system": "resolution" },
+                            "allowedValues": [
+                                { "id": "10000", "name": "Done" },
+                                { "id": "10001", "name": "Won't Do" }
+                            ]
+                        }
+                    }
+                },
+                { "name": "id 없음", "to": { "name": "Closed" } }
+            ]
+        });
+        body["transitions"].as_array().unwrap().iter().filter_map(parse_transition).collect()
+    }
+
+    #[test]
+    fn parses_transitions_with_screen_fields() {
+        let list = transitions();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].to_status_category, "indeterminate");
+        assert!(!list[0].has_screen);
+        assert!(list[0].fields.is_empty());
+        assert!(list[1].has_screen);
+        assert_eq!(list[1].fields[0].id, "resolution");
+    }
+
+    #[test]
+    fn finds_transition_by_id_name_or_status() {
+        let list = transitions();
+        let cases = [
+            ("11", "11"),
+            ("31", "31"),
+            ("진행 시작", "11"),
+            (" resolve ", "31"),
+            ("in progress", "11"),
+            ("완료", "31"),
+        ];
+        for (wanted, expected) in cases {
+            assert_eq!(find_transition(&list, wanted).unwrap().id, expected, "입력: {}", wanted);
+        }
+
+        let error = find_transition(&list, "Reopen").unwrap_err();
+        assert!(error.contains("Resolve → 완료 (id: 31)"), "{}", error);
+        assert!(find_transition(&[], "Done").unwrap_err().contains("없음"));
+    }
+
+    #[test]
+    fn resolves_resolution_by_id_or_name() {
+        let list = transitions();
+        let resolve = &list[1];
+        let cases = [("10001", "10001"), ("done", "10000"), (" Won't Do ", "10001")];
+        for (wanted, expected) in cases {
+            assert_eq!(resolve_resolution(resolve, wanted).unwrap(), json!({ "id": expected }), "입력: {}", wanted);
+        }
+
+        let error = resolve_resolution(resolve, "Duplicate").unwrap_err();
+        assert!(error.contains("Done, Won't Do"), "{}", error);
+        // 전환 화면에 해결 상태 필드가 없는 경우
+        assert!(resolve_resolution(&list[0], "Done").unwrap_err().contains("진행 시작"));
+    }
+}
@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::jira_api::{self, JiraAuth};
+use crate::jira_custom_fields;
+use crate::jira_fields::{self, LookupCache};
+use crate::JiraConfig;
+
+// 기존 이슈 수정 (PUT /issue/{key})
+// 지정한 값만 변경하며, 조회 이후 다른 사람이 수정했으면 덮어쓰지 않고 오류를 반환합니다.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IssuePatch {
+    #[serde(default)]
+    pub summary: Option<String>,
+    // Markdown (v3는 ADF, v2는 위키 마크업으로 변환하여 전송)
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub labels_add: Vec<String>,
+    #[serde(default)]
+    pub labels_remove: Vec<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    // accountId, 이메일 또는 표시 이름 (빈 문자열이면 담당자 해제)
+    #[serde(default)]
+    pub assignee: Option<String>,
+    #[serde(default)]
+    pub custom_fields: HashMap<String, serde_json::Value>,
+    // 조회 시점의 updated 값 (있으면 충돌 검사)
+    #[serde(default)]
+    pub expected_updated: Option<String>,
+}
+
+fn issue_url(config: &JiraConfig, issue_key: &str) -> String {
+    jira_api::api_url(config, &format!("issue/{}", issue_key))
+}
+
+// Jira 타임스탬프(예: 2024-05-01T10:20:30.123+0900)를 비교 가능한 시각으로 변환
+fn parse_timestamp(value: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    chrono::DateTime::parse_from_str(value.trim(), "%Y-%m-%dT%H:%M:%S%.f%z")
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(value.trim()))
+        .ok()
+}
+
+fn same_timestamp(a: &str, b: &str) -> bool {
+    match (parse_timestamp(a), parse_timestamp(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.trim() == b.trim(),
+    }
+}
+
+// 라벨은 전체 교체 대신 추가/삭제 연산으로 전송
+fn label_operations(patch: &IssuePatch) -> Vec<serde_json::Value> {
+    patch
+        .labels_add
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::json!({ "add": l.trim() }))
+        .chain(
+            patch
+                .labels_remove
+                .iter()
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| serde_json::json!({ "remove": l.trim() })),
+        )
+        .collect()
+}
+
+async fn fetch_updated(client: &reqwest::Client, config: &JiraConfig, issue_key: &str) -> Result<String, String> {
+    let response = client
+        .get(issue_url(config, issue_key))
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .query(&[("fields", "updated")])
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    let issue: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 오류: {}", e))?;
+    Ok(issue["fields"]["updated"].as_str().unwrap_or("").to_string())
+}
+
+pub async fn update_issue(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    issue_key: &str,
+    patch: &IssuePatch,
+) -> Result<(), String> {
+    // 낙관적 동시성 제어: 조회 이후 변경되었으면 중단
+    if let Some(expected) = patch.expected_updated.as_deref().filter(|e| !e.trim().is_empty()) {
+        let current = fetch_updated(client, config, issue_key).await?;
+        if !same_timestamp(expected, &current) {
+            return Err(format!(
+                "{} 이슈가 다른 곳에서 수정되었습니다 (조회 시점: {}, 현재: {}). 이슈를 다시 불러온 뒤 수정해주세요.",
+                issue_key, expected, current
+            ));
+        }
+    }
+
+    let mut fields = serde_json::Map::new();
+    let mut lookups = LookupCache::default();
+
+    if let Some(summary) = &patch.summary {
+        if summary.trim().is_empty() {
+            return Err("제목은 비워둘 수 없습니다.".to_string());
+        }
+        fields.insert("summary".to_string(), serde_json::json!(summary.trim()));
+    }
+
+    if let Some(description) = &patch.description {
+        fields.insert("description".to_string(), jira_api::rich_text(config, description));
+    }
+
+    if let Some(priority) = patch.priority.as_deref().filter(|p| !p.trim().is_empty()) {
+        let priority_id = jira_fields::resolve_priority(client, config, priority, &mut lookups)
+            .await?
+            .ok_or_else(|| format!("우선순위 '{}'을(를) Jira에서 찾을 수 없습니다.", priority))?;
+        fields.insert("priority".to_string(), serde_json::json!({ "id": priority_id }));
+    }
+
+    if let Some(assignee) = &patch.assignee {
+        let user_id = if assignee.trim().is_empty() {
+            None
+        } else {
+            Some(jira_fields::resolve_user_id(client, config, assignee, &mut lookups).await?)
+        };
+        fields.insert("assignee".to_string(), jira_api::user_ref(config, user_id.as_deref()));
+    }
+
+    if !patch.custom_fields.is_empty() {
+        let descriptors = jira_custom_fields::fetch_edit_fields(client, config, issue_key).await?;
+        jira_custom_fields::apply_edits(client, config, &descriptors, &patch.custom_fields, &mut lookups, &mut fields)
+            .await?;
+    }
+
+    let label_operations = label_operations(patch);
+    if fields.is_empty() && label_operations.is_empty() {
+        return Err("수정할 내용이 없습니다.".to_string());
+    }
+
+    let mut body = serde_json::json!({ "fields": fields });
+    if !label_operations.is_empty() {
+        body["update"] = serde_json::json!({ "labels": label_operations });
+    }
+
+    let response = client
+        .put(issue_url(config, issue_key))
+        .jira_auth(config)?
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json")
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        println!("Jira 이슈 수정 오류 ({}): {}", status, error_text);
+        return Err(jira_fields::describe_error(status, &error_text));
+    }
+
+    println!("Jira 이슈 수정 완료: {}", issue_key);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn compares_updated_timestamps() {
+        let cases = [
+            ("2024-05-01T10:20:30.123+0900", "2024-05-01T10:20:30.123+0900", true),
+            // 같은 시각을 다른 시간대로 표현
+            ("2024-05-01T10:20:30.123+0900", "2024-05-01T01:20:30.123+0000", true),
+            ("2024-05-01T10:20:30.123+0900", "2024-05-01T01:20:30.123Z", true),
+            (" 2024-05-01T10:20:30.123+0900 ", "2024-05-01T10:20:30.123+0900", true),
+            ("2024-05-01T10:20:30.123+0900", "2024-05-01T10:20:30.124+0900", false),
+            ("2024-05-01T10:20:30.123+0900", "2024-05-01T10:20:30.123+0000", false),
+            // 해석할 수 없으면 문자열 그대로 비교
+            ("어제", "어제", true),
+            ("어제", "2024-05-01T10:20:30.123+0900", false),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(same_timestamp(a, b), expected, "입력: {} / {}", a, b);
+        }
+    }
+
+    #[test]
+    fn builds_label_operations() {
+        let patch = IssuePatch {
+            labels_add: vec![" backend ".to_string(), "".to_string(), "urgent".to_string()],
+            labels_remove: vec!["triage".to_string(), "  ".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            label_operations(&patch),
+            vec![json!({ "add": "backend" }), json!({ "add": "urgent" }), json!({ "remove": "triage" })]
+        );
+
+        assert!(label_operations(&IssuePatch::default()).is_empty());
+    }
+}
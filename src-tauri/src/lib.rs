@@ -4,14 +4,82 @@ use std::fs;
 use std::path::Path;
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
+use tauri::{Emitter, State};
 use tokio::time::timeout as tokio_timeout;
 
+mod adf_render;
+mod ai_cache;
+mod ai_stream;
+mod ai_usage;
+mod attachments;
+mod classifier;
+mod duplicates;
+mod few_shot;
+mod gemini;
+mod http_client;
+mod jira_api;
+mod jira_attachments;
+mod jira_bulk;
+mod jira_comments;
+mod jira_custom_fields;
+mod jira_fields;
+mod jira_links;
+mod jira_meta;
+mod jira_oauth;
+mod jira_search;
+mod jira_transitions;
+mod jira_update;
+mod markdown_adf;
+mod markdown_wiki;
+mod redact;
+mod refine;
+mod sections;
+mod similarity;
+
+use ai_cache::AICacheConfig;
+use ai_stream::{AnalysisDelta, SseParser, ANALYSIS_DELTA_EVENT, ANALYSIS_DONE_EVENT};
+use ai_usage::{ModelPrice, UsageSummary};
+use duplicates::DuplicateCandidate;
+use few_shot::FewShotConfig;
+use gemini::GeminiError;
+use http_client::{HttpClientConfig, HttpClientState};
+use jira_api::{JiraApiVersion, JiraAuth, JiraAuthMode};
+use jira_attachments::AttachmentUploadResult;
+use jira_bulk::{BulkIssueRecord, BulkRowResult};
+use jira_comments::{CommentVisibility, JiraComment, JiraCommentPage};
+use jira_custom_fields::FieldDescriptor;
+use jira_fields::{IssueFieldOptions, LookupCache};
+use jira_links::{IssueLinkRequest, JiraLinkType};
+use jira_meta::JiraIssueType;
+use jira_oauth::{JiraOAuthConfig, JiraOAuthStatus, OAuthSession};
+use jira_search::JiraSearchResult;
+use jira_transitions::{JiraTransition, TransitionRequest};
+use jira_update::IssuePatch;
+use redact::{RedactionConfig, Redactor};
+use refine::RefinedAnalysis;
+use sections::IssueSections;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JiraConfig {
     pub base_url: String,
     pub email: String,
     pub api_token: String,
     pub project_key: String,
+    // 분석 결과 이슈 타입 → Jira 이슈 타입 이름 또는 id (예: "Bug" → "결함")
+    #[serde(default)]
+    pub issue_type_mapping: HashMap<String, String>,
+    // 인증 방식 (Cloud 기본, Server/Data Center는 PAT 또는 사용자 이름/비밀번호)
+    #[serde(default)]
+    pub auth_mode: JiraAuthMode,
+    // Cloud에서 사용할 REST API 버전 (Server/Data Center는 항상 v2)
+    #[serde(default)]
+    pub api_version: JiraApiVersion,
+    // OAuth 2.0 (3LO) 앱 설정 (auth_mode가 cloud_oauth일 때 사용)
+    #[serde(default)]
+    pub oauth: Option<JiraOAuthConfig>,
+    // 로그인 토큰으로 채우는 요청 세션 (저장하지 않음)
+    #[serde(skip)]
+    pub oauth_session: Option<OAuthSession>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,15 +87,37 @@ pub struct AIConfig {
     pub api_key: String,
     pub model: String,
     pub temperature: f32,
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+    // API 키가 없거나 Gemini에 연결할 수 없을 때 로컬 분류기로 대체
+    #[serde(default = "default_offline_fallback")]
+    pub offline_fallback: bool,
+    #[serde(default)]
+    pub cache: AICacheConfig,
+    // 모델별 가격표 (100만 토큰당 USD), 없으면 기본 가격 사용
+    #[serde(default)]
+    pub pricing: HashMap<String, ModelPrice>,
+    #[serde(default)]
+    pub few_shot: FewShotConfig,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+fn default_offline_fallback() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueAnalysis {
     pub title: String,
     pub description: String,
     pub issue_type: String,
     pub priority: String,
     pub labels: Vec<String>,
+    // 로컬 분류기 결과의 신뢰도 (0.0 ~ 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    // 요약, 재현 절차, 기대/실제 결과, 인수 조건, 환경
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sections: Option<IssueSections>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -35,9 +125,48 @@ pub struct JiraIssue {
     pub key: String,
     pub summary: String,
     pub description: String,
+    // 서식을 제거한 설명 (검색, 미리보기용)
+    #[serde(default)]
+    pub description_text: String,
     pub issue_type: String,
     pub priority: String,
     pub status: String,
+    // 마지막 수정 시각 (수정 시 충돌 검사에 사용)
+    #[serde(default)]
+    pub updated: String,
+    // 검색 시 추가로 요청한 필드의 원본 값
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extra_fields: HashMap<String, serde_json::Value>,
+    // 이슈 생성 후 연결 추가에 실패한 항목 (이슈 자체는 생성됨)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub link_errors: Vec<String>,
+}
+
+// JiraIssue를 구성하는 데 필요한 기본 필드
+const ISSUE_BASE_FIELDS: &[&str] = &["summary", "description", "issuetype", "priority", "status", "updated"];
+
+// Jira 이슈 응답(JSON)을 JiraIssue로 변환
+fn parse_jira_issue(issue: &serde_json::Value, extra_fields: &[String]) -> JiraIssue {
+    let fields = &issue["fields"];
+    let text = |value: &serde_json::Value, default: &str| value.as_str().unwrap_or(default).to_string();
+
+    JiraIssue {
+        key: text(&issue["key"], ""),
+        summary: text(&fields["summary"], ""),
+        // 설명 전체를 Markdown과 평문으로 변환 (v3: ADF, v2: 위키 마크업)
+        description: jira_api::rich_text_to_markdown(&fields["description"]),
+        description_text: jira_api::rich_text_to_plain_text(&fields["description"]),
+        issue_type: text(&fields["issuetype"]["name"], "Task"),
+        priority: text(&fields["priority"]["name"], "Medium"),
+        status: text(&fields["status"]["name"], "To Do"),
+        updated: text(&fields["updated"], ""),
+        extra_fields: extra_fields
+            .iter()
+            .filter(|f| !ISSUE_BASE_FIELDS.contains(&f.as_str()))
+            .filter_map(|f| fields.get(f.as_str()).map(|value| (f.clone(), value.clone())))
+            .collect(),
+        link_errors: Vec::new(),
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -60,11 +189,256 @@ pub struct NodeExecutionOutput {
 
 // AI 분석 명령어
 #[tauri::command]
-async fn analyze_with_ai(text: String, config: AIConfig) -> Result<IssueAnalysis, String> {
-    let client = reqwest::Client::new();
-    let url = format!("https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent", config.model);
+async fn analyze_with_ai(
+    text: String,
+    config: AIConfig,
+    bypass_cache: Option<bool>,
+    attachments: Option<Vec<String>>,
+    http: State<'_, HttpClientState>,
+) -> Result<IssueAnalysis, String> {
+    if config.api_key.trim().is_empty() {
+        if config.offline_fallback {
+            println!("Gemini API 키가 없어 로컬 분류기를 사용합니다.");
+            return Ok(classifier::classify(&text));
+        }
+        return Err("Gemini API 키가 설정되지 않았습니다.".to_string());
+    }
+
+    let client = http.client();
+
+    // 첨부 파일은 먼저 읽어서 크기/형식 오류를 요청 전에 알려줍니다.
+    let attachments = attachments::load(&attachments.unwrap_or_default())?;
+    // 첨부 파일이 있는 요청은 텍스트만으로 결과가 정해지지 않으므로 캐시하지 않습니다.
+    let use_cache = attachments.is_empty();
+
+    // 토큰, 이메일, IP 등 민감 정보를 자리표시자로 치환한 뒤 전송
+    let mut redactor = Redactor::new(&config.redaction)?;
+    let redacted_text = redactor.redact(&text);
+
+    let examples = few_shot::select(&config.few_shot, &text);
+    let examples_section = few_shot::render(&config.few_shot, &examples, &redactor);
+
+    // 같은 요청은 캐시된 결과를 재사용하여 할당량을 아끼고 결과를 일관되게 유지
+    let cache_key = ai_cache::cache_key(
+        &redacted_text,
+        &examples_section,
+        &config.model,
+        config.temperature,
+        ANALYSIS_PROMPT_VERSION,
+    );
+    if use_cache && !bypass_cache.unwrap_or(false) {
+        if let Some(cached) = ai_cache::load(&config.cache, &cache_key) {
+            println!("AI 분석 캐시 사용: {}", cache_key);
+            return Ok(redactor.restore_analysis(cached));
+        }
+    }
+
+    let attachment_parts = attachments::to_parts(&attachments, &mut redactor);
+    let request_data = build_analysis_request(
+        &redacted_text,
+        &examples_section,
+        attachment_parts,
+        redactor.has_redactions(),
+        &config,
+    );
     
-    let prompt = format!(
+    let content = match gemini::generate_content(&client, &config, &request_data).await {
+        Ok(content) => content,
+        Err(GeminiError::Unreachable(message)) if config.offline_fallback => {
+            println!("{}, 로컬 분류기로 대체합니다.", message);
+            return Ok(classifier::classify(&text));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let analysis = parse_analysis_content(&content)?;
+    if use_cache {
+        if let Err(e) = ai_cache::store(&config.cache, &cache_key, &config.model, ANALYSIS_PROMPT_VERSION, analysis.clone()) {
+            println!("{}", e);
+        }
+    }
+
+    Ok(redactor.restore_analysis(analysis))
+}
+
+// AI 분석 스트리밍 명령어
+// 생성 중인 텍스트는 ai-analysis-delta 이벤트로, 최종 결과는 ai-analysis-done 이벤트로 전달합니다.
+#[tauri::command]
+async fn analyze_with_ai_stream(
+    app: tauri::AppHandle,
+    text: String,
+    config: AIConfig,
+    bypass_cache: Option<bool>,
+    attachments: Option<Vec<String>>,
+    http: State<'_, HttpClientState>,
+) -> Result<IssueAnalysis, String> {
+    if config.api_key.trim().is_empty() {
+        if config.offline_fallback {
+            println!("Gemini API 키가 없어 로컬 분류기를 사용합니다.");
+            return emit_analysis_done(&app, classifier::classify(&text));
+        }
+        return Err("Gemini API 키가 설정되지 않았습니다.".to_string());
+    }
+
+    let client = http.client();
+    let url = format!("{}/{}:streamGenerateContent", gemini::GEMINI_API_BASE, config.model);
+
+    // 첨부 파일은 먼저 읽어서 크기/형식 오류를 요청 전에 알려줍니다.
+    let attachments = attachments::load(&attachments.unwrap_or_default())?;
+    // 첨부 파일이 있는 요청은 텍스트만으로 결과가 정해지지 않으므로 캐시하지 않습니다.
+    let use_cache = attachments.is_empty();
+
+    let mut redactor = Redactor::new(&config.redaction)?;
+    let redacted_text = redactor.redact(&text);
+
+    let examples = few_shot::select(&config.few_shot, &text);
+    let examples_section = few_shot::render(&config.few_shot, &examples, &redactor);
+
+    let cache_key = ai_cache::cache_key(
+        &redacted_text,
+        &examples_section,
+        &config.model,
+        config.temperature,
+        ANALYSIS_PROMPT_VERSION,
+    );
+    if use_cache && !bypass_cache.unwrap_or(false) {
+        if let Some(cached) = ai_cache::load(&config.cache, &cache_key) {
+            println!("AI 분석 캐시 사용: {}", cache_key);
+            return emit_analysis_done(&app, redactor.restore_analysis(cached));
+        }
+    }
+
+    let attachment_parts = attachments::to_parts(&attachments, &mut redactor);
+    let request_data = build_analysis_request(
+        &redacted_text,
+        &examples_section,
+        attachment_parts,
+        redactor.has_redactions(),
+        &config,
+    );
+
+    let mut response = match client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .query(&[("key", config.api_key.as_str()), ("alt", "sse")])
+        // 스트리밍은 전체 시간 대신 청크 사이 대기 시간으로 제한
+        .timeout(http_client::STREAM_TIMEOUT)
+        .json(&request_data)
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(e) if config.offline_fallback => {
+            println!("Gemini API 네트워크 오류, 로컬 분류기로 대체합니다: {}", e);
+            return emit_analysis_done(&app, classifier::classify(&text));
+        }
+        Err(e) => return Err(format!("Gemini API 네트워크 오류: {}", e)),
+    };
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+
+        println!("Gemini API 오류 ({}): {}", status, error_text);
+
+        if status.is_server_error() && config.offline_fallback {
+            println!("Gemini API 서버 오류, 로컬 분류기로 대체합니다.");
+            return emit_analysis_done(&app, classifier::classify(&text));
+        }
+
+        return Err(gemini::error_message(status, &error_text, &config.model));
+    }
+
+    let chunk_timeout = http.config().request_timeout();
+    let mut parser = SseParser::default();
+    let mut full_text = String::new();
+    let mut finish_reason = String::new();
+    let mut usage_metadata = serde_json::Value::Null;
+    let mut finished = false;
+
+    while !finished {
+        let events = match tokio_timeout(chunk_timeout, response.chunk())
+            .await
+            .map_err(|_| format!("Gemini API 스트림 응답이 {}초 동안 없습니다.", chunk_timeout.as_secs()))?
+            .map_err(|e| format!("Gemini API 스트림 수신 오류: {}", e))?
+        {
+            Some(chunk) => parser.push(&chunk),
+            None => {
+                finished = true;
+                parser.finish().into_iter().collect()
+            }
+        };
+
+        for data in events {
+            if ai_stream::is_done_marker(&data) {
+                continue;
+            }
+
+            let event: serde_json::Value = serde_json::from_str(&data)
+                .map_err(|e| format!("Gemini API 스트림 파싱 오류: {}. 응답 내용: {}", e, data))?;
+
+            if let Some(reason) = ai_stream::extract_finish_reason(&event) {
+                finish_reason = reason;
+            }
+
+            // 사용량은 마지막 청크에 누적값으로 포함됩니다.
+            if event["usageMetadata"].is_object() {
+                usage_metadata = event["usageMetadata"].clone();
+            }
+
+            if let Some(delta) = ai_stream::extract_delta_text(&event).filter(|d| !d.is_empty()) {
+                full_text.push_str(&delta);
+                app.emit(ANALYSIS_DELTA_EVENT, AnalysisDelta {
+                    delta: redactor.restore(&delta),
+                    text: redactor.restore(&full_text),
+                })
+                .map_err(|e| format!("이벤트 전송 실패: {}", e))?;
+            }
+        }
+    }
+
+    println!("Finish reason: {}", finish_reason);
+
+    if let Err(e) = ai_usage::record(&config.model, &usage_metadata, &config.pricing) {
+        println!("{}", e);
+    }
+
+    if finish_reason == "MAX_TOKENS" {
+        return Err("응답이 너무 길어서 잘렸습니다. 더 간단한 요청을 시도해주세요.".to_string());
+    }
+    if full_text.trim().is_empty() {
+        return Err("응답에서 텍스트를 찾을 수 없습니다.".to_string());
+    }
+
+    let analysis = parse_analysis_content(&full_text)?;
+    if use_cache {
+        if let Err(e) = ai_cache::store(&config.cache, &cache_key, &config.model, ANALYSIS_PROMPT_VERSION, analysis.clone()) {
+            println!("{}", e);
+        }
+    }
+
+    emit_analysis_done(&app, redactor.restore_analysis(analysis))
+}
+
+// 최종 결과(캐시, 로컬 분류기 대체 포함)를 ai-analysis-done 이벤트로 보내고 반환
+fn emit_analysis_done(app: &tauri::AppHandle, analysis: IssueAnalysis) -> Result<IssueAnalysis, String> {
+    app.emit(ANALYSIS_DONE_EVENT, &analysis)
+        .map_err(|e| format!("이벤트 전송 실패: {}", e))?;
+    Ok(analysis)
+}
+
+// 프롬프트 내용이 바뀌면 올려서 이전 캐시를 무효화합니다.
+const ANALYSIS_PROMPT_VERSION: u32 = 3;
+
+// Gemini 분석 요청 본문 구성
+fn build_analysis_request(
+    text: &str,
+    examples: &str,
+    attachment_parts: Vec<serde_json::Value>,
+    keep_placeholders: bool,
+    config: &AIConfig,
+) -> serde_json::Value {
+    let mut prompt = format!(
         "다음 사용자 요청을 분석하여 Jira 이슈로 변환해주세요:\n\n\
         사용자 요청: {}\n\n\
         다음 JSON 형식으로 응답해주세요:\n\
@@ -73,7 +447,15 @@ async fn analyze_with_ai(text: String, config: AIConfig) -> Result<IssueAnalysis
             \"description\": \"상세 설명 (한국어, 사용자 요청을 바탕으로 구체적으로 작성)\",\n\
             \"issue_type\": \"Bug|Task|Story|Epic 중 하나\",\n\
             \"priority\": \"Low|Medium|High|Critical 중 하나\",\n\
-            \"labels\": [\"관련 라벨1\", \"관련 라벨2\"]\n\
+            \"labels\": [\"관련 라벨1\", \"관련 라벨2\"],\n\
+            \"sections\": {{\n\
+                \"summary\": \"한두 문장 요약\",\n\
+                \"steps_to_reproduce\": [\"재현 단계1\", \"재현 단계2\"],\n\
+                \"expected_result\": \"기대 결과\",\n\
+                \"actual_result\": \"실제 결과\",\n\
+                \"acceptance_criteria\": [\"인수 조건1\", \"인수 조건2\"],\n\
+                \"environment\": \"OS, 브라우저, 앱 버전 등 환경 정보\"\n\
+            }}\n\
         }}\n\n\
         분석 기준:\n\
         - 버그 관련 키워드가 있으면 Bug 타입\n\
@@ -82,151 +464,227 @@ async fn analyze_with_ai(text: String, config: AIConfig) -> Result<IssueAnalysis
         - 큰 프로젝트나 여러 기능을 포함하면 Epic 타입\n\
         - 긴급하거나 중요한 내용이면 High/Critical 우선순위\n\
         - 일반적인 내용이면 Medium 우선순위\n\
-        - 간단한 내용이면 Low 우선순위",
+        - 간단한 내용이면 Low 우선순위\n\
+        - Bug는 steps_to_reproduce, expected_result, actual_result, environment를 반드시 작성\n\
+        - Story는 acceptance_criteria를 반드시 작성\n\
+        - 요청에서 알 수 없는 섹션 값은 빈 문자열 또는 빈 배열로 두기",
         text
     );
-    
-    let request_data = serde_json::json!({
+
+    if keep_placeholders {
+        prompt.push_str("\n- [REDACTED_...] 형태의 자리표시자는 수정하지 말고 그대로 유지");
+    }
+
+    if !attachment_parts.is_empty() {
+        prompt.push_str("\n- 첨부된 스크린샷과 로그 파일의 내용(화면 상태, 오류 메시지)도 설명에 반영");
+    }
+
+    prompt.push_str(examples);
+
+    let mut parts = vec![serde_json::json!({
+        "text": format!("당신은 Jira 이슈 관리 전문가입니다. 사용자의 자연어 요청을 분석하여 적절한 Jira 이슈로 변환해주세요.\n\n{}", prompt)
+    })];
+    parts.extend(attachment_parts);
+
+    serde_json::json!({
         "contents": [
             {
-                "parts": [
-                    {
-                        "text": format!("당신은 Jira 이슈 관리 전문가입니다. 사용자의 자연어 요청을 분석하여 적절한 Jira 이슈로 변환해주세요.\n\n{}", prompt)
-                    }
-                ]
+                "parts": parts
             }
         ],
         "generationConfig": {
             "temperature": config.temperature,
-            "maxOutputTokens": 2000
+            "maxOutputTokens": 4000
         }
-    });
-    
-    let response = client
-        .post(&url)
-        .header("Content-Type", "application/json")
-        .query(&[("key", &config.api_key)])
-        .json(&request_data)
-        .send()
-        .await
-        .map_err(|e| format!("Gemini API 네트워크 오류: {}", e))?;
-    
-    if response.status().is_success() {
-        let response_data: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("Gemini API 응답 파싱 오류: {}", e))?;
+    })
+}
 
-        println!("Gemini API 응답: {:?}", response_data);
-        
-        // 응답 구조 분석
-        let candidates = &response_data["candidates"];
-        if candidates.is_array() && !candidates.as_array().unwrap().is_empty() {
-            let candidate = &candidates[0];
-            let finish_reason = candidate["finishReason"].as_str().unwrap_or("");
-            
-            println!("Finish reason: {}", finish_reason);
-            
-            // finishReason이 MAX_TOKENS인 경우 처리
-            if finish_reason == "MAX_TOKENS" {
-                return Err("응답이 너무 길어서 잘렸습니다. 더 간단한 요청을 시도해주세요.".to_string());
-            }
-            
-            // content에서 텍스트 추출
-            if let Some(content_obj) = candidate["content"].as_object() {
-                if let Some(parts) = content_obj["parts"].as_array() {
-                    if !parts.is_empty() {
-                        if let Some(text) = parts[0]["text"].as_str() {
-                            // 코드 블록 제거 (```json ... ```)
-                            let content = if text.starts_with("```json") && text.ends_with("```") {
-                                // ```json과 ``` 제거
-                                let start = text.find('\n').unwrap_or(0) + 1;
-                                let end = text.rfind("```").unwrap_or(text.len());
-                                &text[start..end]
-                            } else if text.starts_with("```") && text.ends_with("```") {
-                                // 일반 코드 블록 제거
-                                let start = text.find('\n').unwrap_or(0) + 1;
-                                let end = text.rfind("```").unwrap_or(text.len());
-                                &text[start..end]
-                            } else {
-                                text
-                            };
-                            
-                            println!("정리된 JSON: {}", content);
-                            
-                            // JSON 파싱 시도
-                            let analysis: IssueAnalysis = serde_json::from_str(content)
-                                .map_err(|e| format!("AI 응답 JSON 파싱 오류: {}. 응답 내용: {}", e, content))?;
-                            
-                            return Ok(analysis);
-                        } else {
-                            return Err("응답에서 텍스트를 찾을 수 없습니다.".to_string());
-                        }
-                    } else {
-                        return Err("응답 parts가 비어있습니다.".to_string());
-                    }
-                } else {
-                    return Err("응답 content 구조가 올바르지 않습니다.".to_string());
+// AI 응답 텍스트에서 IssueAnalysis 추출
+fn parse_analysis_content(text: &str) -> Result<IssueAnalysis, String> {
+    let text = text.trim();
+
+    // 코드 블록 제거 (```json ... ```)
+    let content = if text.starts_with("```json") && text.ends_with("```") {
+        // ```json과 ``` 제거
+        let start = text.find('\n').unwrap_or(0) + 1;
+        let end = text.rfind("```").unwrap_or(text.len());
+        &text[start..end]
+    } else if text.starts_with("```") && text.ends_with("```") {
+        // 일반 코드 블록 제거
+        let start = text.find('\n').unwrap_or(0) + 1;
+        let end = text.rfind("```").unwrap_or(text.len());
+        &text[start..end]
+    } else {
+        text
+    };
+
+    println!("정리된 JSON: {}", content);
+
+    // JSON 파싱 시도
+    serde_json::from_str(content)
+        .map_err(|e| format!("AI 응답 JSON 파싱 오류: {}. 응답 내용: {}", e, content))
+}
+
+// 기존 분석 결과를 후속 지시에 따라 수정하는 명령어
+#[tauri::command]
+async fn refine_analysis(analysis: IssueAnalysis, instruction: String, config: AIConfig, http: State<'_, HttpClientState>) -> Result<RefinedAnalysis, String> {
+    if instruction.trim().is_empty() {
+        return Err("수정 지시 내용이 비어있습니다.".to_string());
+    }
+    if config.api_key.trim().is_empty() {
+        return Err("Gemini API 키가 설정되지 않았습니다.".to_string());
+    }
+
+    let client = http.client();
+    let mut redactor = Redactor::new(&config.redaction)?;
+    let request_data = refine::build_request(&analysis, &instruction, &mut redactor, &config);
+
+    let content = gemini::generate_content(&client, &config, &request_data).await?;
+    let refined = redactor.restore_analysis(parse_analysis_content(&content)?);
+    let changes = refine::diff(&analysis, &refined);
+
+    Ok(RefinedAnalysis {
+        analysis: refined,
+        changes,
+    })
+}
+
+// AI 분석 캐시 삭제 명령어
+#[tauri::command]
+async fn clear_ai_cache() -> Result<usize, String> {
+    ai_cache::clear()
+}
+
+// AI 사용량 조회 명령어 (날짜/모델별 집계, 날짜 형식: YYYY-MM-DD)
+#[tauri::command]
+async fn get_ai_usage(from: Option<String>, to: Option<String>) -> Result<Vec<UsageSummary>, String> {
+    let parse_date = |value: Option<String>| -> Result<Option<chrono::NaiveDate>, String> {
+        value
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| {
+                chrono::NaiveDate::parse_from_str(v.trim(), "%Y-%m-%d")
+                    .map_err(|e| format!("날짜 형식 오류 ({}): {}", v, e))
+            })
+            .transpose()
+    };
+
+    ai_usage::summarize(parse_date(from)?, parse_date(to)?)
+}
+
+// 사용자가 최종 등록한 이슈를 예시로 저장하는 명령어 (few-shot 프롬프트에 사용)
+#[tauri::command]
+async fn record_accepted_issue(text: String, analysis: IssueAnalysis, config: AIConfig) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Err("저장할 요청 내용이 비어있습니다.".to_string());
+    }
+    // 분석 요청과 같은 마스킹 규칙(사용자 정의 패턴 포함)으로 저장
+    few_shot::record(&text, analysis, &config.redaction)
+}
+
+// 로컬 분류기 명령어 (AI 없이 즉시 분석)
+#[tauri::command]
+async fn classify_issue_offline(text: String) -> Result<IssueAnalysis, String> {
+    if text.trim().is_empty() {
+        return Err("분석할 내용이 비어있습니다.".to_string());
+    }
+    Ok(classifier::classify(&text))
+}
+
+// 분석 결과와 옵션으로 이슈 생성 요청의 fields를 구성하고 createmeta 기준으로 검증
+// descriptor_cache: 이슈 타입 id → 생성 화면 필드 (일괄 생성 시 재사용)
+// lookups: 우선순위, 사용자, 컴포넌트/버전 조회 결과 (일괄 생성 시 재사용)
+async fn build_issue_fields(
+    client: &reqwest::Client,
+    config: &JiraConfig,
+    analysis: &IssueAnalysis,
+    options: &IssueFieldOptions,
+    issue_types: &[JiraIssueType],
+    descriptor_cache: &mut HashMap<String, Vec<FieldDescriptor>>,
+    lookups: &mut LookupCache,
+) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let parent = options.parent.as_deref().map(str::trim).filter(|p| !p.is_empty());
+
+    // 분석된 이슈 타입을 프로젝트의 실제 이슈 타입으로 변환 (하위 작업 요청 시 하위 작업 타입)
+    let issue_type = if options.subtask {
+        jira_meta::find_subtask_type(issue_types)?
+    } else {
+        jira_meta::resolve_issue_type(&analysis.issue_type, issue_types, &config.issue_type_mapping)?
+    };
+    if issue_type.subtask && parent.is_none() {
+        return Err(format!("'{}' 타입으로 만들려면 상위 이슈 키(parent)가 필요합니다.", issue_type.name));
+    }
+
+    // Markdown 설명 아래에 구조화된 섹션(제목, 번호 목록, 체크리스트)을 이어붙임 (v3: ADF, v2: 위키 마크업)
+    let description = match jira_api::api_version(config) {
+        JiraApiVersion::V3 => {
+            let mut description = markdown_adf::markdown_to_adf(&analysis.description);
+            if let Some(sections) = analysis.sections.as_ref().filter(|s| !s.is_empty()) {
+                if let Some(content) = description["content"].as_array_mut() {
+                    content.extend(sections.to_adf_nodes());
                 }
-            } else {
-                return Err("응답 content가 없습니다.".to_string());
             }
-        } else {
-            return Err("응답 candidates가 없습니다.".to_string());
+            description
         }
-    } else {
-        let status = response.status();
-        let error_text = response.text().await.unwrap_or_default();
-        
-        println!("Gemini API 오류 ({}): {}", status, error_text);
-        
-        // 404 오류인 경우 모델 관련 안내 메시지 추가
-        if status == 404 {
-            Err(format!("Gemini API 모델 오류: '{}' 모델을 찾을 수 없습니다. 지원되는 모델: gemini-2.0-flash-exp, gemini-2.5-pro, gemini-2.5-flash, gemini-2.5-flash-lite, gemini-live-2.5-flash-preview, gemini-2.0-flash-live-001", config.model))
-        } else {
-            Err(format!("Gemini API 오류 ({}): {}", status, error_text))
+        JiraApiVersion::V2 => {
+            let mut markdown = analysis.description.clone();
+            if let Some(sections) = analysis.sections.as_ref().filter(|s| !s.is_empty()) {
+                markdown.push_str("\n\n");
+                markdown.push_str(&sections.to_markdown());
+            }
+            jira_api::rich_text(config, &markdown)
         }
+    };
+
+    let mut fields = serde_json::Map::new();
+    fields.insert("project".to_string(), serde_json::json!({ "key": config.project_key }));
+    fields.insert("summary".to_string(), serde_json::json!(analysis.title));
+    fields.insert("description".to_string(), description);
+    fields.insert("issuetype".to_string(), serde_json::json!({ "id": issue_type.id }));
+    fields.insert("labels".to_string(), serde_json::json!(analysis.labels));
+
+    // 커스텀 필드는 이슈 타입별 생성 화면 스키마에 맞춰 검증/변환
+    if !descriptor_cache.contains_key(&issue_type.id) {
+        let descriptors = jira_custom_fields::fetch_create_fields(client, config, &issue_type.id).await?;
+        descriptor_cache.insert(issue_type.id.clone(), descriptors);
     }
+    let field_descriptors = &descriptor_cache[&issue_type.id];
+    jira_fields::apply_to_fields(client, config, &analysis.priority, options, lookups, &mut fields).await?;
+    jira_custom_fields::apply_to_fields(client, config, field_descriptors, &options.custom_fields, lookups, &mut fields)
+        .await?;
+
+    Ok(fields)
 }
 
 // Jira 이슈 생성 명령어
 #[tauri::command]
-async fn create_jira_issue(analysis: IssueAnalysis, config: JiraConfig) -> Result<JiraIssue, String> {
-    let client = reqwest::Client::new();
-    let url = format!("{}/rest/api/3/issue", config.base_url.trim_end_matches('/'));
-    
-    // Jira 이슈 생성 요청 데이터 구성
-    let issue_data = serde_json::json!({
-        "fields": {
-            "project": {
-                "key": config.project_key
-            },
-            "summary": analysis.title,
-            "description": {
-                "type": "doc",
-                "version": 1,
-                "content": [
-                    {
-                        "type": "paragraph",
-                        "content": [
-                            {
-                                "type": "text",
-                                "text": analysis.description
-                            }
-                        ]
-                    }
-                ]
-            },
-            "issuetype": {
-                "name": "버그"
-            },
-            "labels": analysis.labels
-        }
-    });
+async fn create_jira_issue(
+    analysis: IssueAnalysis,
+    config: JiraConfig,
+    options: Option<IssueFieldOptions>,
+    http: State<'_, HttpClientState>,
+) -> Result<JiraIssue, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    let url = jira_api::api_url(&config, "issue");
     
+    // 우선순위, 담당자, 보고자, 컴포넌트, 수정 버전, 기한, 상위 이슈, 연결 (이름 → Jira id 변환)
+    let options = options.unwrap_or_default();
+    let issue_types = jira_meta::fetch_issue_types(&client, &config).await?;
+    let fields = build_issue_fields(
+        &client,
+        &config,
+        &analysis,
+        &options,
+        &issue_types,
+        &mut HashMap::new(),
+        &mut LookupCache::default(),
+    )
+    .await?;
+    let issue_data = serde_json::json!({ "fields": fields });
+
     let response = client
         .post(&url)
-        .basic_auth(&config.email, Some(&config.api_token))
+        .jira_auth(&config)?
         .header("Accept", "application/json")
         .header("Content-Type", "application/json")
         .json(&issue_data)
@@ -245,8 +703,15 @@ async fn create_jira_issue(analysis: IssueAnalysis, config: JiraConfig) -> Resul
             .ok_or("응답에서 이슈 키를 찾을 수 없습니다")?
             .to_string();
         
+        // 이슈는 이미 생성되었으므로 연결 실패는 오류 대신 결과에 담아 알려줍니다. (재시도로 중복 생성 방지)
+        let link_errors = jira_links::try_link_issues(&client, &config, &key, &options.links).await;
+        for error in &link_errors {
+            println!("{} 이슈 연결 실패: {}", key, error);
+        }
+
         // 생성된 이슈의 상세 정보 조회
-        let issue_detail = get_jira_issue_detail(&client, &config, &key).await?;
+        let mut issue_detail = get_jira_issue_detail(&client, &config, &key).await?;
+        issue_detail.link_errors = link_errors;
         
         Ok(issue_detail)
     } else {
@@ -254,17 +719,50 @@ async fn create_jira_issue(analysis: IssueAnalysis, config: JiraConfig) -> Resul
         let error_text = response.text().await.unwrap_or_default();
         
         println!("Jira API 오류 ({}): {}", status, error_text);
-        Err(format!("Jira API 오류 ({}): {}", status, error_text))
+        Err(jira_fields::describe_error(status, &error_text))
     }
 }
 
+// 일괄 이슈 생성 명령어 (레코드 목록 또는 CSV/JSON 파일 + 열 매핑)
+#[tauri::command]
+async fn bulk_create_jira_issues(
+    config: JiraConfig,
+    records: Option<Vec<BulkIssueRecord>>,
+    file_path: Option<String>,
+    column_mapping: Option<HashMap<String, String>>,
+    http: State<'_, HttpClientState>,
+) -> Result<Vec<BulkRowResult>, String> {
+    let records = match (records, file_path) {
+        (Some(records), _) => records.into_iter().map(Ok).collect(),
+        (None, Some(path)) => jira_bulk::load_records(&path, &column_mapping.unwrap_or_default())?,
+        (None, None) => return Err("생성할 레코드 또는 파일 경로를 지정해주세요.".to_string()),
+    };
+
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_bulk::bulk_create(&client, &config, records).await
+}
+
+// 이슈 생성 전 중복 후보 검색 명령어
+#[tauri::command]
+async fn find_duplicate_issues(
+    analysis: IssueAnalysis,
+    config: JiraConfig,
+    limit: Option<usize>,
+    http: State<'_, HttpClientState>,
+) -> Result<Vec<DuplicateCandidate>, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    duplicates::find_duplicates(&client, &config, &analysis, limit.unwrap_or(5)).await
+}
+
 // Jira 이슈 상세 정보 조회
 async fn get_jira_issue_detail(client: &reqwest::Client, config: &JiraConfig, issue_key: &str) -> Result<JiraIssue, String> {
-    let url = format!("{}/rest/api/3/issue/{}", config.base_url.trim_end_matches('/'), issue_key);
+    let url = jira_api::api_url(config, &format!("issue/{}", issue_key));
     
     let response = client
         .get(&url)
-        .basic_auth(&config.email, Some(&config.api_token))
+        .jira_auth(config)?
         .header("Accept", "application/json")
         .send()
         .await
@@ -276,46 +774,7 @@ async fn get_jira_issue_detail(client: &reqwest::Client, config: &JiraConfig, is
             .await
             .map_err(|e| format!("응답 파싱 오류: {}", e))?;
         
-        let fields = &issue_data["fields"];
-        
-        let summary = fields["summary"]
-            .as_str()
-            .unwrap_or("")
-            .to_string();
-        
-        let description = fields["description"]
-            .as_object()
-            .and_then(|desc| desc["content"].as_array())
-            .and_then(|content| content.first())
-            .and_then(|para| para["content"].as_array())
-            .and_then(|content| content.first())
-            .and_then(|text| text["text"].as_str())
-            .unwrap_or("")
-            .to_string();
-        
-        let issue_type = fields["issuetype"]["name"]
-            .as_str()
-            .unwrap_or("Task")
-            .to_string();
-        
-        let priority = fields["priority"]["name"]
-            .as_str()
-            .unwrap_or("Medium")
-            .to_string();
-        
-        let status = fields["status"]["name"]
-            .as_str()
-            .unwrap_or("To Do")
-            .to_string();
-        
-        Ok(JiraIssue {
-            key: issue_key.to_string(),
-            summary,
-            description,
-            issue_type,
-            priority,
-            status,
-        })
+        Ok(parse_jira_issue(&issue_data, &[]))
     } else {
         let status = response.status();
         let error_text = response.text().await.unwrap_or_default();
@@ -323,16 +782,151 @@ async fn get_jira_issue_detail(client: &reqwest::Client, config: &JiraConfig, is
     }
 }
 
+// JQL 검색 명령어 (페이지를 자동으로 넘기며 limit까지 수집)
+#[tauri::command]
+async fn search_jira_issues(
+    jql: String,
+    config: JiraConfig,
+    fields: Option<Vec<String>>,
+    page_token: Option<String>,
+    limit: Option<usize>,
+    http: State<'_, HttpClientState>,
+) -> Result<JiraSearchResult, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_search::search_issues(&client, &config, &jql, &fields.unwrap_or_default(), page_token, limit).await
+}
+
+// 기존 이슈 수정 명령어 (수정 후 최신 상태를 반환)
+#[tauri::command]
+async fn update_jira_issue(issue_key: String, patch: IssuePatch, config: JiraConfig, http: State<'_, HttpClientState>) -> Result<JiraIssue, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_update::update_issue(&client, &config, &issue_key, &patch).await?;
+    get_jira_issue_detail(&client, &config, &issue_key).await
+}
+
+// 이슈에서 가능한 워크플로 전환 목록 조회 명령어
+#[tauri::command]
+async fn get_jira_transitions(issue_key: String, config: JiraConfig, http: State<'_, HttpClientState>) -> Result<Vec<JiraTransition>, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_transitions::fetch_transitions(&client, &config, &issue_key).await
+}
+
+// 이슈 상태 전환 명령어 (전환 후 최신 상태를 반환)
+#[tauri::command]
+async fn transition_jira_issue(
+    issue_key: String,
+    request: TransitionRequest,
+    config: JiraConfig,
+    http: State<'_, HttpClientState>,
+) -> Result<JiraIssue, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_transitions::transition_issue(&client, &config, &issue_key, &request).await?;
+    get_jira_issue_detail(&client, &config, &issue_key).await
+}
+
+// 이슈 댓글 목록 조회 명령어 (start_at부터 limit개, 남은 댓글이 있으면 next_start_at 반환)
+#[tauri::command]
+async fn list_jira_comments(
+    issue_key: String,
+    config: JiraConfig,
+    start_at: Option<usize>,
+    limit: Option<usize>,
+    http: State<'_, HttpClientState>,
+) -> Result<JiraCommentPage, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_comments::list_comments(&client, &config, &issue_key, start_at, limit).await
+}
+
+// 이슈 댓글 작성 명령어 (Markdown 본문)
+#[tauri::command]
+async fn add_jira_comment(
+    issue_key: String,
+    body: String,
+    visibility: Option<CommentVisibility>,
+    config: JiraConfig,
+    http: State<'_, HttpClientState>,
+) -> Result<JiraComment, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_comments::add_comment(&client, &config, &issue_key, &body, visibility).await
+}
+
+// 이슈에 파일 첨부 명령어 (파일별 결과 반환)
+#[tauri::command]
+async fn attach_files_to_issue(
+    issue_key: String,
+    paths: Vec<String>,
+    config: JiraConfig,
+    http: State<'_, HttpClientState>,
+) -> Result<Vec<AttachmentUploadResult>, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_attachments::attach_files(&client, &config, &issue_key, &paths).await
+}
+
+// 서버에서 사용 가능한 이슈 연결 유형 조회 명령어
+#[tauri::command]
+async fn get_jira_link_types(config: JiraConfig, http: State<'_, HttpClientState>) -> Result<Vec<JiraLinkType>, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_links::fetch_link_types(&client, &config).await
+}
+
+// 기존 이슈에 연결 추가 명령어
+#[tauri::command]
+async fn link_jira_issues(issue_key: String, links: Vec<IssueLinkRequest>, config: JiraConfig, http: State<'_, HttpClientState>) -> Result<(), String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_links::link_issues(&client, &config, &issue_key, &links).await
+}
+
+// 현재 HTTP 클라이언트 설정 조회 명령어
+#[tauri::command]
+async fn get_http_client_config(http: State<'_, HttpClientState>) -> Result<HttpClientConfig, String> {
+    Ok(http.config())
+}
+
+// HTTP 클라이언트 설정(타임아웃, 프록시, 루트 인증서, User-Agent) 저장 및 적용 명령어
+#[tauri::command]
+async fn set_http_client_config(config: HttpClientConfig, http: State<'_, HttpClientState>) -> Result<(), String> {
+    http.apply(config)
+}
+
+// Jira Cloud OAuth 로그인 명령어 (브라우저 로그인 후 토큰 저장)
+#[tauri::command]
+async fn jira_oauth_login(config: JiraConfig, http: State<'_, HttpClientState>) -> Result<JiraOAuthStatus, String> {
+    let client = http.client();
+    jira_oauth::login(&client, &config).await
+}
+
+// 저장된 OAuth 로그인 상태 조회 명령어 (로그인 전이면 None)
+#[tauri::command]
+async fn get_jira_oauth_status() -> Result<Option<JiraOAuthStatus>, String> {
+    jira_oauth::status()
+}
+
+// OAuth 로그아웃 명령어 (저장된 토큰 삭제)
+#[tauri::command]
+async fn jira_oauth_logout() -> Result<(), String> {
+    jira_oauth::logout().await
+}
+
 // Jira 연결 테스트 명령어
 #[tauri::command]
-async fn test_jira_connection(config: JiraConfig) -> Result<bool, String> {
+async fn test_jira_connection(config: JiraConfig, http: State<'_, HttpClientState>) -> Result<bool, String> {
     // 실제 Jira API 연결 테스트
-    let client = reqwest::Client::new();
-    let url = format!("{}/rest/api/3/myself", config.base_url.trim_end_matches('/'));
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    let url = jira_api::api_url(&config, "myself");
     
     let response = client
         .get(&url)
-        .basic_auth(&config.email, Some(&config.api_token))
+        .jira_auth(&config)?
         .header("Accept", "application/json").send().await.map_err(|e| format!("네트워크 오류: {}", e))?;
     
     if response.status().is_success() {
@@ -430,13 +1024,14 @@ async fn read_log_file(filename: String) -> Result<String, String> {
 
 // Jira 프로젝트의 이슈 타입 조회 명령어
 #[tauri::command]
-async fn get_jira_issue_types(config: JiraConfig) -> Result<Vec<serde_json::Value>, String> {
-    let client = reqwest::Client::new();
-    let url = format!("{}/rest/api/3/project/{}/statuses", config.base_url.trim_end_matches('/'), config.project_key);
+async fn get_jira_issue_types(config: JiraConfig, http: State<'_, HttpClientState>) -> Result<Vec<serde_json::Value>, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    let url = jira_api::api_url(&config, &format!("project/{}/statuses", config.project_key));
     
     let response = client
         .get(&url)
-        .basic_auth(&config.email, Some(&config.api_token))
+        .jira_auth(&config)?
         .header("Accept", "application/json")
         .send()
         .await
@@ -456,15 +1051,34 @@ async fn get_jira_issue_types(config: JiraConfig) -> Result<Vec<serde_json::Valu
     }
 }
 
+// 이슈 생성에 사용할 수 있는 프로젝트 이슈 타입 조회 명령어 (createmeta)
+#[tauri::command]
+async fn get_jira_create_issue_types(config: JiraConfig, http: State<'_, HttpClientState>) -> Result<Vec<JiraIssueType>, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    jira_meta::fetch_issue_types(&client, &config).await
+}
+
+// 이슈 타입별 생성 화면 필드(커스텀 필드 포함) 조회 명령어
+#[tauri::command]
+async fn get_jira_create_fields(config: JiraConfig, issue_type: String, http: State<'_, HttpClientState>) -> Result<Vec<FieldDescriptor>, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    let issue_types = jira_meta::fetch_issue_types(&client, &config).await?;
+    let issue_type = jira_meta::resolve_issue_type(&issue_type, &issue_types, &config.issue_type_mapping)?;
+    jira_custom_fields::fetch_create_fields(&client, &config, &issue_type.id).await
+}
+
 // Jira 프로젝트 메타데이터 조회 명령어
 #[tauri::command]
-async fn get_jira_project_metadata(config: JiraConfig) -> Result<serde_json::Value, String> {
-    let client = reqwest::Client::new();
-    let url = format!("{}/rest/api/3/project/{}/metadata", config.base_url.trim_end_matches('/'), config.project_key);
+async fn get_jira_project_metadata(config: JiraConfig, http: State<'_, HttpClientState>) -> Result<serde_json::Value, String> {
+    let client = http.client();
+    let config = jira_oauth::authorize(&client, config).await?;
+    let url = jira_api::api_url(&config, &format!("project/{}/metadata", config.project_key));
     
     let response = client
         .get(&url)
-        .basic_auth(&config.email, Some(&config.api_token))
+        .jira_auth(&config)?
         .header("Accept", "application/json")
         .send()
         .await
@@ -596,12 +1210,37 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
+        .manage(HttpClientState::load())
         .invoke_handler(tauri::generate_handler![
             greet,
             analyze_with_ai,
+            analyze_with_ai_stream,
+            refine_analysis,
+            classify_issue_offline,
+            clear_ai_cache,
+            get_ai_usage,
+            record_accepted_issue,
             create_jira_issue,
+            bulk_create_jira_issues,
+            find_duplicate_issues,
+            search_jira_issues,
+            update_jira_issue,
+            get_jira_transitions,
+            transition_jira_issue,
+            list_jira_comments,
+            add_jira_comment,
+            attach_files_to_issue,
+            get_jira_link_types,
+            link_jira_issues,
+            get_http_client_config,
+            set_http_client_config,
+            jira_oauth_login,
+            get_jira_oauth_status,
+            jira_oauth_logout,
             test_jira_connection,
             get_jira_issue_types,
+            get_jira_create_issue_types,
+            get_jira_create_fields,
             get_jira_project_metadata,
             save_settings,
             load_settings,
@@ -0,0 +1,529 @@
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+use serde_json::Value;
+
+// Markdown → Atlassian Document Format 변환
+// AI 응답과 사용자 입력은 Markdown이므로 이슈 설명과 댓글을 보내기 전에 ADF로 변환합니다.
+
+// 인라인 노드만 자식으로 가질 수 있는 블록
+const INLINE_CONTAINERS: &[&str] = &["paragraph", "heading", "taskItem", "codeBlock"];
+
+struct Frame {
+    node: Value,
+    // taskItem 안에 중첩된 블록(하위 목록 등)은 ADF 규칙상 항목 뒤에 형제로 배치합니다.
+    trailing: Vec<Value>,
+}
+
+struct Converter {
+    frames: Vec<Frame>,
+    marks: Vec<Value>,
+    in_table_head: bool,
+}
+
+// taskList/taskItem에 필요한 고유 ID
+pub fn local_id() -> String {
+    format!("{:016x}", rand::random::<u64>())
+}
+
+fn node_type(node: &Value) -> &str {
+    node["type"].as_str().unwrap_or("")
+}
+
+fn block(kind: &str) -> Value {
+    serde_json::json!({ "type": kind, "content": [] })
+}
+
+fn block_with_attrs(kind: &str, attrs: Value) -> Value {
+    serde_json::json!({ "type": kind, "attrs": attrs, "content": [] })
+}
+
+fn text_node(text: &str, marks: Vec<Value>) -> Value {
+    if marks.is_empty() {
+        serde_json::json!({ "type": "text", "text": text })
+    } else {
+        serde_json::json!({ "type": "text", "text": text, "marks": marks })
+    }
+}
+
+fn content_mut(node: &mut Value) -> &mut Vec<Value> {
+    if !node["content"].is_array() {
+        node["content"] = Value::Array(vec![]);
+    }
+    node["content"].as_array_mut().expect("content는 배열입니다")
+}
+
+// 같은 서식의 연속된 텍스트는 하나의 노드로 합칩니다.
+fn push_inline_into(content: &mut Vec<Value>, node: Value) {
+    if let Some(last) = content.last_mut() {
+        if node_type(last) == "text" && node_type(&node) == "text" && last.get("marks") == node.get("marks") {
+            let merged = format!("{}{}", last["text"].as_str().unwrap_or(""), node["text"].as_str().unwrap_or(""));
+            last["text"] = Value::String(merged);
+            return;
+        }
+    }
+    content.push(node);
+}
+
+impl Converter {
+    fn new() -> Self {
+        Self {
+            frames: vec![Frame {
+                node: serde_json::json!({ "type": "doc", "version": 1, "content": [] }),
+                trailing: vec![],
+            }],
+            marks: vec![],
+            in_table_head: false,
+        }
+    }
+
+    fn top(&mut self) -> &mut Frame {
+        self.frames.last_mut().expect("doc 프레임은 항상 존재합니다")
+    }
+
+    fn top_type(&self) -> &str {
+        self.frames.last().map(|f| node_type(&f.node)).unwrap_or("")
+    }
+
+    fn open(&mut self, node: Value) {
+        self.frames.push(Frame { node, trailing: vec![] });
+    }
+
+    fn append_block(&mut self, node: Value) {
+        let top = self.top();
+        if node_type(&top.node) == "taskItem" {
+            top.trailing.push(node);
+        } else {
+            content_mut(&mut top.node).push(node);
+        }
+    }
+
+    fn push_inline(&mut self, node: Value) {
+        let top = self.top();
+        if INLINE_CONTAINERS.contains(&node_type(&top.node)) {
+            push_inline_into(content_mut(&mut top.node), node);
+            return;
+        }
+
+        // 목록 항목, 인용, 표 셀 등에 바로 온 인라인은 문단으로 감쌉니다.
+        let content = content_mut(&mut top.node);
+        match content.last_mut() {
+            Some(last) if node_type(last) == "paragraph" => push_inline_into(content_mut(last), node),
+            _ => content.push(serde_json::json!({ "type": "paragraph", "content": [node] })),
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        // 코드 블록 안의 텍스트에는 서식을 적용할 수 없습니다.
+        let marks = if self.top_type() == "codeBlock" { vec![] } else { self.marks.clone() };
+        self.push_inline(text_node(text, marks));
+    }
+
+    fn close(&mut self) {
+        if self.frames.len() <= 1 {
+            return;
+        }
+        let frame = self.frames.pop().expect("닫을 프레임이 있습니다");
+        if let Some(node) = finalize(frame.node) {
+            self.append_block(node);
+        }
+        for node in frame.trailing {
+            self.append_block(node);
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                if self.top_type() == "taskItem" {
+                    // taskItem은 인라인만 가질 수 있으므로 문단 대신 줄바꿈으로 구분
+                    let top = self.top();
+                    if !content_mut(&mut top.node).is_empty() {
+                        content_mut(&mut top.node).push(serde_json::json!({ "type": "hardBreak" }));
+                    }
+                } else {
+                    self.open(block("paragraph"));
+                }
+            }
+            Tag::Heading(level, _, _) => self.open(block_with_attrs("heading", serde_json::json!({ "level": level as u8 }))),
+            Tag::BlockQuote => self.open(block("blockquote")),
+            Tag::CodeBlock(kind) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or("").to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                if language.is_empty() {
+                    self.open(block("codeBlock"));
+                } else {
+                    self.open(block_with_attrs("codeBlock", serde_json::json!({ "language": language })));
+                }
+            }
+            Tag::List(Some(start)) if start != 1 => {
+                self.open(block_with_attrs("orderedList", serde_json::json!({ "order": start })))
+            }
+            Tag::List(Some(_)) => self.open(block("orderedList")),
+            Tag::List(None) => self.open(block("bulletList")),
+            Tag::Item => self.open(block("listItem")),
+            Tag::Table(_) => self.open(block_with_attrs(
+                "table",
+                serde_json::json!({ "isNumberColumnEnabled": false, "layout": "default" }),
+            )),
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.open(block("tableRow"));
+            }
+            Tag::TableRow => self.open(block("tableRow")),
+            Tag::TableCell => {
+                let kind = if self.in_table_head { "tableHeader" } else { "tableCell" };
+                self.open(block_with_attrs(kind, serde_json::json!({})));
+            }
+            Tag::Emphasis => self.marks.push(serde_json::json!({ "type": "em" })),
+            Tag::Strong => self.marks.push(serde_json::json!({ "type": "strong" })),
+            Tag::Strikethrough => self.marks.push(serde_json::json!({ "type": "strike" })),
+            // 이미지는 미디어 업로드가 필요하므로 링크로 대체
+            Tag::Link(_, url, title) | Tag::Image(_, url, title) => {
+                let mut attrs = serde_json::json!({ "href": url.to_string() });
+                if !title.is_empty() {
+                    attrs["title"] = Value::String(title.to_string());
+                }
+                self.marks.push(serde_json::json!({ "type": "link", "attrs": attrs }));
+            }
+            Tag::FootnoteDefinition(_) => {}
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                if self.top_type() == "paragraph" {
+                    self.close();
+                }
+            }
+            Tag::TableHead => {
+                self.in_table_head = false;
+                self.close();
+            }
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link(..) | Tag::Image(..) => {
+                self.marks.pop();
+            }
+            Tag::FootnoteDefinition(_) => {}
+            _ => self.close(),
+        }
+    }
+
+    fn task_marker(&mut self, checked: bool) {
+        // 느슨한 목록에서는 표시가 항목의 첫 문단 안에서 나타납니다.
+        if self.top_type() == "paragraph" && self.frames.len() >= 2 {
+            let is_empty = self.frames.last().is_some_and(|f| f.node["content"].as_array().is_none_or(|c| c.is_empty()));
+            let parent_is_item = node_type(&self.frames[self.frames.len() - 2].node) == "listItem";
+            if is_empty && parent_is_item {
+                self.frames.pop();
+            }
+        }
+
+        if self.top_type() == "listItem" {
+            let state = if checked { "DONE" } else { "TODO" };
+            let top = self.top();
+            let existing = std::mem::take(content_mut(&mut top.node));
+            top.node = serde_json::json!({
+                "type": "taskItem",
+                "attrs": { "localId": local_id(), "state": state },
+                "content": existing
+            });
+        }
+    }
+
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.push_text(&text),
+            Event::Code(code) => {
+                // 인라인 코드 서식은 링크와만 함께 사용할 수 있습니다.
+                let mut marks: Vec<Value> = self.marks.iter().filter(|m| node_type(m) == "link").cloned().collect();
+                marks.push(serde_json::json!({ "type": "code" }));
+                self.push_inline(text_node(&code, marks));
+            }
+            Event::Html(html) => self.push_text(&html),
+            Event::FootnoteReference(label) => self.push_text(&format!("[^{}]", label)),
+            Event::SoftBreak | Event::HardBreak => {
+                if self.top_type() == "codeBlock" {
+                    self.push_text("\n");
+                } else {
+                    self.push_inline(serde_json::json!({ "type": "hardBreak" }));
+                }
+            }
+            Event::Rule => self.append_block(serde_json::json!({ "type": "rule" })),
+            Event::TaskListMarker(checked) => self.task_marker(checked),
+        }
+    }
+
+    fn finish(mut self) -> Value {
+        while self.frames.len() > 1 {
+            self.close();
+        }
+        self.frames.pop().map(|f| f.node).unwrap_or(Value::Null)
+    }
+}
+
+// 블록을 닫을 때 ADF 스키마 규칙에 맞게 정리
+fn finalize(mut node: Value) -> Option<Value> {
+    match node_type(&node).to_string().as_str() {
+        "paragraph" if node["content"].as_array().is_none_or(|c| c.is_empty()) => None,
+        "codeBlock" => {
+            let content = content_mut(&mut node);
+            if let Some(last) = content.last_mut() {
+                let trimmed = last["text"].as_str().unwrap_or("").trim_end_matches('\n').to_string();
+                last["text"] = Value::String(trimmed);
+            }
+            content.retain(|n| n["text"].as_str().is_some_and(|t| !t.is_empty()));
+            Some(node)
+        }
+        "listItem" | "tableCell" | "tableHeader" => {
+            let content = content_mut(&mut node);
+            if content.is_empty() {
+                content.push(block("paragraph"));
+            }
+            Some(node)
+        }
+        "bulletList" | "orderedList" => Some(finalize_list(node)),
+        _ => Some(node),
+    }
+}
+
+// 모든 항목이 체크박스면 taskList로, 일부만 체크박스면 일반 목록 항목으로 변환
+fn finalize_list(mut node: Value) -> Value {
+    let items = std::mem::take(content_mut(&mut node));
+    let has_task = items.iter().any(|n| node_type(n) == "taskItem");
+    let all_task = items
+        .iter()
+        .all(|n| node_type(n) == "taskItem" || node_type(n) == "taskList");
+
+    if has_task && all_task {
+        return serde_json::json!({
+            "type": "taskList",
+            "attrs": { "localId": local_id() },
+            "content": items
+        });
+    }
+
+    let mut converted: Vec<Value> = Vec::new();
+    for item in items {
+        match node_type(&item) {
+            "taskItem" => {
+                let checked = item["attrs"]["state"].as_str() == Some("DONE");
+                let mut inline = vec![text_node(if checked { "☑ " } else { "☐ " }, vec![])];
+                for node in item["content"].as_array().cloned().unwrap_or_default() {
+                    push_inline_into(&mut inline, node);
+                }
+                converted.push(serde_json::json!({
+                    "type": "listItem",
+                    "content": [{ "type": "paragraph", "content": inline }]
+                }));
+            }
+            "listItem" => converted.push(item),
+            // 체크박스 항목 뒤에 배치된 하위 블록은 직전 항목 안으로 되돌립니다.
+            _ => match converted.last_mut() {
+                Some(last) => content_mut(last).push(item),
+                None => converted.push(serde_json::json!({ "type": "listItem", "content": [item] })),
+            },
+        }
+    }
+    node["content"] = Value::Array(converted);
+    node
+}
+
+pub fn markdown_to_adf(markdown: &str) -> Value {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut converter = Converter::new();
+    for event in Parser::new_ext(markdown, options) {
+        converter.handle(event);
+    }
+    converter.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adf_render::adf_to_markdown;
+    use serde_json::json;
+
+    // 무작위 localId는 비교에서 제외
+    fn without_local_ids(mut value: Value) -> Value {
+        match &mut value {
+            Value::Object(map) => {
+                if let Some(Value::Object(attrs)) = map.get_mut("attrs") {
+                    attrs.remove("localId");
+                }
+                for child in map.values_mut() {
+                    *child = without_local_ids(child.take());
+                }
+            }
+            Value::Array(items) => {
+                for item in items.iter_mut() {
+                    *item = without_local_ids(item.take());
+                }
+            }
+            _ => {}
+        }
+        value
+    }
+
+    fn content(markdown: &str) -> Value {
+        without_local_ids(markdown_to_adf(markdown))["content"].clone()
+    }
+
+    fn paragraph(text: &str) -> Value {
+        json!({ "type": "paragraph", "content": [{ "type": "text", "text": text }] })
+    }
+
+    #[test]
+    fn converts_headings() {
+        assert_eq!(
+            content("# 제목\n\n### 소제목"),
+            json!([
+                { "type": "heading", "attrs": { "level": 1 }, "content": [{ "type": "text", "text": "제목" }] },
+                { "type": "heading", "attrs": { "level": 3 }, "content": [{ "type": "text", "text": "소제목" }] }
+            ])
+        );
+    }
+
+    #[test]
+    fn converts_marks_and_links() {
+        assert_eq!(
+            content("**굵게** *기울임* ~~취소~~ `코드` [Jira](https://example.com)"),
+            json!([{
+                "type": "paragraph",
+                "content": [
+                    { "type": "text", "text": "굵게", "marks": [{ "type": "strong" }] },
+                    { "type": "text", "text": " " },
+                    { "type": "text", "text": "기울임", "marks": [{ "type": "em" }] },
+                    { "type": "text", "text": " " },
+                    { "type": "text", "text": "취소", "marks": [{ "type": "strike" }] },
+                    { "type": "text", "text": " " },
+                    { "type": "text", "text": "코드", "marks": [{ "type": "code" }] },
+                    { "type": "text", "text": " " },
+                    {
+                        "type": "text",
+                        "text": "Jira",
+                        "marks": [{ "type": "link", "attrs": { "href": "https://example.com" } }]
+                    }
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn converts_nested_lists() {
+        assert_eq!(
+            content("- 하나\n  1. 둘\n- 셋"),
+            json!([{
+                "type": "bulletList",
+                "content": [
+                    {
+                        "type": "listItem",
+                        "content": [
+                            paragraph("하나"),
+                            { "type": "orderedList", "content": [{ "type": "listItem", "content": [paragraph("둘")] }] }
+                        ]
+                    },
+                    { "type": "listItem", "content": [paragraph("셋")] }
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn keeps_ordered_list_start() {
+        assert_eq!(content("3. 셋째\n4. 넷째")[0]["attrs"], json!({ "order": 3 }));
+    }
+
+    #[test]
+    fn converts_task_lists() {
+        let adf = markdown_to_adf("- [ ] 할 일\n- [x] 완료");
+        assert!(adf["content"][0]["attrs"]["localId"].is_string());
+        assert_eq!(
+            without_local_ids(adf)["content"],
+            json!([{
+                "type": "taskList",
+                "attrs": {},
+                "content": [
+                    { "type": "taskItem", "attrs": { "state": "TODO" }, "content": [{ "type": "text", "text": "할 일" }] },
+                    { "type": "taskItem", "attrs": { "state": "DONE" }, "content": [{ "type": "text", "text": "완료" }] }
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn mixed_task_items_become_list_items() {
+        assert_eq!(
+            content("- [x] 완료\n- 일반"),
+            json!([{
+                "type": "bulletList",
+                "content": [
+                    {
+                        "type": "listItem",
+                        "content": [{
+                            "type": "paragraph",
+                            "content": [{ "type": "text", "text": "☑ 완료" }]
+                        }]
+                    },
+                    { "type": "listItem", "content": [paragraph("일반")] }
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn converts_code_blocks_with_language() {
+        assert_eq!(
+            content("```rust\nfn main() {\n    *x = 1;\n}\n```"),
+            json!([{
+                "type": "codeBlock",
+                "attrs": { "language": "rust" },
+                "content": [{ "type": "text", "text": "fn main() {\n    *x = 1;\n}" }]
+            }])
+        );
+        assert_eq!(content("```\nplain\n```")[0].get("attrs"), None);
+    }
+
+    #[test]
+    fn converts_tables() {
+        let cell = |kind: &str, text: &str| json!({ "type": kind, "attrs": {}, "content": [paragraph(text)] });
+        assert_eq!(
+            content("| A | B |\n| --- | --- |\n| 1 | 2 |"),
+            json!([{
+                "type": "table",
+                "attrs": { "isNumberColumnEnabled": false, "layout": "default" },
+                "content": [
+                    { "type": "tableRow", "content": [cell("tableHeader", "A"), cell("tableHeader", "B")] },
+                    { "type": "tableRow", "content": [cell("tableCell", "1"), cell("tableCell", "2")] }
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn empty_input_is_empty_document() {
+        assert_eq!(markdown_to_adf(""), json!({ "type": "doc", "version": 1, "content": [] }));
+    }
+
+    #[test]
+    fn round_trips_through_adf_render() {
+        let cases = [
+            "# 제목\n\n본문 **굵게** *기울임* ~~취소~~ `코드`",
+            "[Jira](https://example.com) 링크",
+            "- 하나\n  - 둘\n- 셋",
+            "1. 첫째\n2. 둘째",
+            "- [ ] 할 일\n- [x] 완료",
+            "```rust\nfn main() {}\n```",
+            "| A | B |\n| --- | --- |\n| 1 | 2 |",
+            "> 인용\n\n---\n\n### 소제목",
+        ];
+        for markdown in cases {
+            assert_eq!(adf_to_markdown(&markdown_to_adf(markdown)), markdown);
+        }
+    }
+}
@@ -0,0 +1,687 @@
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+use regex::Regex;
+use std::sync::OnceLock;
+
+// Markdown ↔ Jira 위키 마크업 변환 (REST API v2, Server/Data Center용)
+
+struct Converter {
+    out: String,
+    // 중첩 목록 표시 (* 또는 #)
+    lists: Vec<char>,
+    // 링크 주소 (닫을 때 [텍스트|주소]로 출력)
+    links: Vec<String>,
+    in_code_block: bool,
+    in_image: bool,
+    in_table_head: bool,
+    quote_depth: usize,
+}
+
+fn escape_wiki(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '{' | '}' | '[' | ']' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl Converter {
+    fn new() -> Self {
+        Self {
+            out: String::new(),
+            lists: vec![],
+            links: vec![],
+            in_code_block: false,
+            in_image: false,
+            in_table_head: false,
+            quote_depth: 0,
+        }
+    }
+
+    fn ensure_newline(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    // 블록 끝: 목록 안에서는 줄바꿈만, 그 외에는 빈 줄로 구분
+    fn end_block(&mut self) {
+        self.ensure_newline();
+        if self.lists.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {}
+            Tag::Heading(level, _, _) => {
+                self.ensure_newline();
+                self.out.push_str(&format!("h{}. ", level as u8));
+            }
+            Tag::BlockQuote => {
+                self.ensure_newline();
+                if self.quote_depth == 0 {
+                    self.out.push_str("{quote}\n");
+                }
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.ensure_newline();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or("").to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                if language.is_empty() {
+                    self.out.push_str("{code}\n");
+                } else {
+                    self.out.push_str(&format!("{{code:{}}}\n", language));
+                }
+                self.in_code_block = true;
+            }
+            Tag::List(start) => {
+                self.ensure_newline();
+                self.lists.push(if start.is_some() { '#' } else { '*' });
+            }
+            Tag::Item => {
+                self.ensure_newline();
+                let marker: String = self.lists.iter().collect();
+                self.out.push_str(&format!("{} ", marker));
+            }
+            Tag::Table(_) => self.ensure_newline(),
+            Tag::TableHead => self.in_table_head = true,
+            Tag::TableRow => {}
+            Tag::TableCell => {
+                let separator = if self.in_table_head { "||" } else { "|" };
+                if self.out.ends_with('\n') || self.out.is_empty() {
+                    self.out.push_str(separator);
+                }
+            }
+            Tag::Emphasis => self.out.push('_'),
+            Tag::Strong => self.out.push('*'),
+            Tag::Strikethrough => self.out.push('-'),
+            Tag::Link(_, url, _) => {
+                self.out.push('[');
+                self.links.push(url.to_string());
+            }
+            Tag::Image(_, url, _) => {
+                self.out.push_str(&format!("!{}!", url));
+                self.in_image = true;
+            }
+            Tag::FootnoteDefinition(_) => {}
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::Heading(..) => self.end_block(),
+            Tag::BlockQuote => {
+                self.quote_depth -= 1;
+                if self.quote_depth == 0 {
+                    self.out.truncate(self.out.trim_end().len());
+                    self.ensure_newline();
+                    self.out.push_str("{quote}");
+                    self.end_block();
+                }
+            }
+            Tag::CodeBlock(_) => {
+                self.in_code_block = false;
+                self.ensure_newline();
+                self.out.push_str("{code}");
+                self.end_block();
+            }
+            Tag::List(_) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.end_block();
+                }
+            }
+            Tag::Item => self.ensure_newline(),
+            Tag::Table(_) => self.end_block(),
+            Tag::TableHead => {
+                self.in_table_head = false;
+                self.out.push('\n');
+            }
+            Tag::TableRow => self.out.push('\n'),
+            Tag::TableCell => self.out.push_str(if self.in_table_head { "||" } else { "|" }),
+            Tag::Emphasis => self.out.push('_'),
+            Tag::Strong => self.out.push('*'),
+            Tag::Strikethrough => self.out.push('-'),
+            Tag::Link(..) => {
+                let url = self.links.pop().unwrap_or_default();
+                self.out.push_str(&format!("|{}]", url));
+            }
+            Tag::Image(..) => self.in_image = false,
+            Tag::FootnoteDefinition(_) => {}
+        }
+    }
+
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => self.out.push_str(&text),
+            // 이미지 대체 텍스트는 위키 마크업에서 표현하지 않음
+            Event::Text(_) if self.in_image => {}
+            Event::Text(text) => self.out.push_str(&escape_wiki(&text)),
+            Event::Code(code) => self.out.push_str(&format!("{{{{{}}}}}", code)),
+            Event::Html(html) => self.out.push_str(&html),
+            Event::FootnoteReference(label) => self.out.push_str(&format!("\\[^{}\\]", label)),
+            Event::SoftBreak | Event::HardBreak => self.out.push('\n'),
+            Event::Rule => {
+                self.ensure_newline();
+                self.out.push_str("----");
+                self.end_block();
+            }
+            // 위키 마크업에는 체크박스가 없으므로 기호로 표시
+            Event::TaskListMarker(checked) => self.out.push_str(if checked { "☑ " } else { "☐ " }),
+        }
+    }
+}
+
+pub fn markdown_to_wiki(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut converter = Converter::new();
+    for event in Parser::new_ext(markdown, options) {
+        converter.handle(event);
+    }
+    converter.out.trim_end().to_string()
+}
+
+// Jira 위키 마크업 → Markdown 변환 (v2 응답의 설명/댓글을 읽을 때 사용)
+// 수정 후 저장하면 markdown_to_wiki로 다시 변환되므로 서식이 이중으로 이스케이프되지 않도록 합니다.
+
+fn heading_regex() -> &'static Regex {
+    static HEADING: OnceLock<Regex> = OnceLock::new();
+    HEADING.get_or_init(|| Regex::new(r"^h([1-6])\.\s+(.*)$").expect("제목 정규식 오류"))
+}
+
+fn list_regex() -> &'static Regex {
+    static LIST: OnceLock<Regex> = OnceLock::new();
+    LIST.get_or_init(|| Regex::new(r"^(?:([*#]+)|(-))\s+(.*)$").expect("목록 정규식 오류"))
+}
+
+fn ordered_line_regex() -> &'static Regex {
+    static ORDERED: OnceLock<Regex> = OnceLock::new();
+    ORDERED.get_or_init(|| Regex::new(r"^(\d+)([.)])(\s)").expect("번호 정규식 오류"))
+}
+
+fn push_markdown_escaped(out: &mut String, c: char) {
+    if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '~' | '|') {
+        out.push('\\');
+    }
+    out.push(c);
+}
+
+fn code_span(code: &str) -> String {
+    let longest = code.split(|c| c != '`').map(|run| run.len()).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{} {} {}", fence, code, fence)
+    } else {
+        format!("{}{}{}", fence, code, fence)
+    }
+}
+
+fn find_from(chars: &[char], from: usize, pattern: &[char]) -> Option<usize> {
+    (from..chars.len().saturating_sub(pattern.len() - 1)).find(|&i| chars[i..].starts_with(pattern))
+}
+
+// 단어 경계에서 열고 닫는 서식 기호(*굵게*, _기울임_, -취소선- 등)의 닫는 위치
+fn find_closing_mark(chars: &[char], open: usize) -> Option<usize> {
+    let mark = chars[open];
+    let before_ok = open == 0 || !chars[open - 1].is_alphanumeric();
+    let next = *chars.get(open + 1)?;
+    if !before_ok || next.is_whitespace() || next == mark {
+        return None;
+    }
+    (open + 2..chars.len()).find(|&j| {
+        chars[j] == mark
+            && !chars[j - 1].is_whitespace()
+            && chars[j - 1] != '\\'
+            && chars.get(j + 1).is_none_or(|c| !c.is_alphanumeric())
+    })
+}
+
+fn convert_link(inner: &str) -> String {
+    if let Some((alias, target)) = inner.split_once('|') {
+        let target = target.trim();
+        let target = if target.contains(' ') || target.contains(')') {
+            format!("<{}>", target)
+        } else {
+            target.to_string()
+        };
+        return format!("[{}]({})", convert_inline(alias), target);
+    }
+    if let Some(user) = inner.strip_prefix('~') {
+        let mut mention = String::from("@");
+        for c in user.trim_start_matches("accountid:").chars() {
+            push_markdown_escaped(&mut mention, c);
+        }
+        return mention;
+    }
+    if inner.contains("://") || inner.starts_with("mailto:") {
+        return format!("<{}>", inner.trim());
+    }
+    format!("\\[{}\\]", convert_inline(inner))
+}
+
+fn convert_inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            // 위키 이스케이프 (\\는 강제 줄바꿈)
+            '\\' => {
+                match chars.get(i + 1) {
+                    Some('\\') => out.push('\n'),
+                    Some(&next) => push_markdown_escaped(&mut out, next),
+                    None => push_markdown_escaped(&mut out, '\\'),
+                }
+                i += 2;
+                continue;
+            }
+            '{' if chars.get(i + 1) == Some(&'{') => {
+                if let Some(end) = find_from(&chars, i + 2, &['}', '}']) {
+                    let code: String = chars[i + 2..end].iter().collect();
+                    out.push_str(&code_span(&code));
+                    i = end + 2;
+                    continue;
+                }
+            }
+            // {color:red}...{color} 같은 표현할 수 없는 인라인 매크로는 제거
+            '{' => {
+                if let Some(end) = find_from(&chars, i + 1, &['}']) {
+                    let name: String = chars[i + 1..end].iter().collect();
+                    if name.split(':').next() == Some("color") {
+                        i = end + 1;
+                        continue;
+                    }
+                }
+            }
+            '[' => {
+                if let Some(end) = (i + 1..chars.len()).find(|&j| chars[j] == ']' && chars[j - 1] != '\\') {
+                    let inner: String = chars[i + 1..end].iter().collect();
+                    out.push_str(&convert_link(&inner));
+                    i = end + 1;
+                    continue;
+                }
+            }
+            '!' if chars.get(i + 1).is_some_and(|c| !c.is_whitespace()) => {
+                if let Some(end) = (i + 1..chars.len()).find(|&j| chars[j] == '!') {
+                    let inner: String = chars[i + 1..end].iter().collect();
+                    let source = inner.split('|').next().unwrap_or("");
+                    if !source.contains(char::is_whitespace) && (source.contains('.') || source.contains("://")) {
+                        out.push_str(&format!("![]({})", source));
+                        i = end + 1;
+                        continue;
+                    }
+                }
+            }
+            '*' | '_' | '-' | '+' | '^' | '~' => {
+                if let Some(end) = find_closing_mark(&chars, i) {
+                    let inner: String = chars[i + 1..end].iter().collect();
+                    let delimiter = match c {
+                        '*' => "**",
+                        '_' => "*",
+                        '-' => "~~",
+                        // 밑줄, 위/아래 첨자는 Markdown에 없으므로 텍스트만 남김
+                        _ => "",
+                    };
+                    out.push_str(&format!("{}{}{}", delimiter, convert_inline(&inner), delimiter));
+                    i = end + 1;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        push_markdown_escaped(&mut out, c);
+        i += 1;
+    }
+    out
+}
+
+// 문단 줄 맨 앞에서 Markdown 블록으로 해석될 수 있는 기호를 이스케이프
+fn escape_line_start(line: String) -> String {
+    let trimmed = line.trim();
+    if line.starts_with('#') || line.starts_with("+ ") {
+        return format!("\\{}", line);
+    }
+    if !trimmed.is_empty() && (trimmed.chars().all(|c| c == '-') || trimmed.chars().all(|c| c == '=')) {
+        return format!("\\{}", line);
+    }
+    ordered_line_regex().replace(&line, "$1\\$2$3").to_string()
+}
+
+fn is_block_start(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("{code")
+        || trimmed.starts_with("{noformat")
+        || trimmed.starts_with("{quote}")
+        || trimmed.starts_with("bq. ")
+        || trimmed.starts_with('|')
+        || trimmed.trim_end() == "----"
+        || heading_regex().is_match(trimmed)
+        || list_regex().is_match(trimmed)
+}
+
+// {code}/{noformat} 블록: 여는 태그 뒤부터 닫는 태그 앞까지
+fn take_code_block(lines: &[&str], start: usize) -> (String, usize) {
+    let first = lines[start].trim_start();
+    let tag = if first.starts_with("{noformat") { "{noformat}" } else { "{code}" };
+    let header_end = first.find('}').unwrap_or(first.len() - 1);
+    let language = if tag == "{code}" {
+        first[1..header_end]
+            .strip_prefix("code:")
+            .and_then(|params| params.split('|').find(|p| !p.contains('=')))
+            .unwrap_or("")
+            .trim()
+            .to_string()
+    } else {
+        String::new()
+    };
+
+    let mut body: Vec<String> = Vec::new();
+    let mut rest = first[header_end + 1..].to_string();
+    let mut index = start;
+    loop {
+        if let Some(end) = rest.find(tag) {
+            body.push(rest[..end].to_string());
+            break;
+        }
+        body.push(rest);
+        index += 1;
+        if index >= lines.len() {
+            break;
+        }
+        rest = lines[index].to_string();
+    }
+
+    // 여는 태그 줄이 비어 있으면 제외
+    if body.first().is_some_and(|l| l.trim().is_empty()) {
+        body.remove(0);
+    }
+    if body.last().is_some_and(|l| l.trim().is_empty()) {
+        body.pop();
+    }
+    let code = body.join("\n");
+    let longest = code.split(|c| c != '`').map(|run| run.len()).max().unwrap_or(0);
+    let fence = "`".repeat((longest + 1).max(3));
+    (format!("{}{}\n{}\n{}", fence, language, code, fence), index + 1)
+}
+
+fn take_quote(lines: &[&str], start: usize) -> (String, usize) {
+    let mut body: Vec<String> = Vec::new();
+    let mut rest = lines[start].trim_start()["{quote}".len()..].to_string();
+    let mut index = start;
+    loop {
+        if let Some(end) = rest.find("{quote}") {
+            body.push(rest[..end].to_string());
+            break;
+        }
+        body.push(rest);
+        index += 1;
+        if index >= lines.len() {
+            break;
+        }
+        rest = lines[index].to_string();
+    }
+
+    let inner = wiki_to_markdown(&body.join("\n"));
+    let quoted = inner
+        .lines()
+        .map(|line| if line.is_empty() { ">".to_string() } else { format!("> {}", line) })
+        .collect::<Vec<_>>()
+        .join("\n");
+    (quoted, index + 1)
+}
+
+fn take_list(lines: &[&str], start: usize) -> (String, usize) {
+    // 열린 목록 단계별 (번호 목록 여부, 현재 번호)
+    let mut levels: Vec<(bool, usize)> = Vec::new();
+    let mut out: Vec<String> = Vec::new();
+    let mut index = start;
+
+    while index < lines.len() {
+        let Some(captures) = list_regex().captures(lines[index].trim_start()) else {
+            break;
+        };
+        let markers = captures.get(1).map(|m| m.as_str()).unwrap_or("*");
+        let ordered = markers.ends_with('#');
+        let depth = markers.chars().count();
+
+        levels.truncate(depth);
+        while levels.len() < depth {
+            levels.push((ordered, 0));
+        }
+        if levels[depth - 1].0 != ordered {
+            levels[depth - 1] = (ordered, 0);
+        }
+        levels[depth - 1].1 += 1;
+
+        // 하위 항목은 상위 항목 표시의 너비만큼 들여쓰기
+        let indent: usize = levels[..depth - 1]
+            .iter()
+            .map(|(ordered, number)| if *ordered { format!("{}. ", number).len() } else { 2 })
+            .sum();
+        let marker = if ordered { format!("{}.", levels[depth - 1].1) } else { "-".to_string() };
+
+        let text = &captures[3];
+        let content = if let Some(rest) = text.strip_prefix("☐ ") {
+            format!("[ ] {}", convert_inline(rest))
+        } else if let Some(rest) = text.strip_prefix("☑ ") {
+            format!("[x] {}", convert_inline(rest))
+        } else {
+            convert_inline(text)
+        };
+        out.push(format!("{}{} {}", " ".repeat(indent), marker, content));
+        index += 1;
+    }
+    (out.join("\n"), index)
+}
+
+// 표 행을 셀로 나눔 (링크 [a|b], {{코드}}, 이스케이프 안의 |는 구분자가 아님)
+fn split_cells(row: &str) -> (bool, Vec<String>) {
+    let row = row.trim();
+    let header = row.starts_with("||");
+    let chars: Vec<char> = row.chars().collect();
+    let mut cells = Vec::new();
+    let mut current = String::new();
+    let mut bracket_depth = 0usize;
+    let mut in_code = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' && i + 1 < chars.len() {
+            current.push(c);
+            current.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+        if c == '{' && chars.get(i + 1) == Some(&'{') && !in_code {
+            in_code = true;
+        } else if c == '}' && chars.get(i + 1) == Some(&'}') && in_code {
+            in_code = false;
+        } else if c == '[' {
+            bracket_depth += 1;
+        } else if c == ']' {
+            bracket_depth = bracket_depth.saturating_sub(1);
+        } else if c == '|' && bracket_depth == 0 && !in_code {
+            cells.push(std::mem::take(&mut current));
+            while chars.get(i + 1) == Some(&'|') {
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+        current.push(c);
+        i += 1;
+    }
+    cells.push(current);
+
+    // 행 앞뒤의 구분자로 생긴 빈 칸 제거
+    if cells.first().is_some_and(|c| c.trim().is_empty()) {
+        cells.remove(0);
+    }
+    if cells.last().is_some_and(|c| c.trim().is_empty()) {
+        cells.pop();
+    }
+    (header, cells.iter().map(|cell| convert_inline(cell.trim()).replace('\n', "<br>")).collect())
+}
+
+fn take_table(lines: &[&str], start: usize) -> (String, usize) {
+    let mut rows: Vec<(bool, Vec<String>)> = Vec::new();
+    let mut index = start;
+    while index < lines.len() && lines[index].trim_start().starts_with('|') {
+        rows.push(split_cells(lines[index]));
+        index += 1;
+    }
+
+    let columns = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(1).max(1);
+    let render_row = |cells: &[String]| {
+        let mut padded = cells.to_vec();
+        padded.resize(columns, String::new());
+        format!("| {} |", padded.join(" | "))
+    };
+
+    let mut out = Vec::new();
+    // Markdown 표는 머리글 행이 필요하므로 없으면 빈 머리글을 만듦
+    let body = match rows.first() {
+        Some((true, cells)) => {
+            out.push(render_row(cells));
+            &rows[1..]
+        }
+        _ => {
+            out.push(render_row(&[]));
+            &rows[..]
+        }
+    };
+    out.push(format!("|{}", " --- |".repeat(columns)));
+    for (_, cells) in body {
+        out.push(render_row(cells));
+    }
+    (out.join("\n"), index)
+}
+
+pub fn wiki_to_markdown(wiki: &str) -> String {
+    let normalized = wiki.replace("\r\n", "\n");
+    let lines: Vec<&str> = normalized.lines().collect();
+    let mut blocks: Vec<String> = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            index += 1;
+            continue;
+        }
+
+        let (block, next) = if trimmed.starts_with("{code") || trimmed.starts_with("{noformat") {
+            take_code_block(&lines, index)
+        } else if trimmed.starts_with("{quote}") {
+            take_quote(&lines, index)
+        } else if trimmed == "----" {
+            ("---".to_string(), index + 1)
+        } else if let Some(rest) = trimmed.strip_prefix("bq. ") {
+            (format!("> {}", convert_inline(rest)), index + 1)
+        } else if let Some(captures) = heading_regex().captures(trimmed) {
+            let level: usize = captures[1].parse().unwrap_or(1);
+            (format!("{} {}", "#".repeat(level), convert_inline(&captures[2])), index + 1)
+        } else if list_regex().is_match(trimmed) {
+            take_list(&lines, index)
+        } else if trimmed.starts_with('|') {
+            take_table(&lines, index)
+        } else {
+            // 빈 줄이나 다른 블록이 나올 때까지 한 문단 (문단 안의 줄바꿈은 유지)
+            let mut paragraph = Vec::new();
+            let mut next = index;
+            while next < lines.len() && !lines[next].trim().is_empty() && (next == index || !is_block_start(lines[next])) {
+                paragraph.push(escape_line_start(convert_inline(lines[next].trim())));
+                next += 1;
+            }
+            (paragraph.join("\n"), next)
+        };
+
+        blocks.push(block);
+        index = next;
+    }
+
+    blocks.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_markdown_to_wiki() {
+        let cases = [
+            ("a_b [x] {y}", "a\\_b \\[x\\] \\{y\\}"),
+            ("# 제목\n\n**굵게** *기울임* ~~취소~~ `code`", "h1. 제목\n\n*굵게* _기울임_ -취소- {{code}}"),
+            ("[Jira](https://example.com)", "[Jira|https://example.com]"),
+            ("- 하나\n  - 둘\n- 셋", "* 하나\n** 둘\n* 셋"),
+            ("1. 첫째\n2. 둘째\n   - 하위", "# 첫째\n# 둘째\n#* 하위"),
+            ("- [ ] 할 일\n- [x] 완료", "* ☐ 할 일\n* ☑ 완료"),
+            ("```rust\nfn main() {}\n```", "{code:rust}\nfn main() {}\n{code}"),
+            ("| A | B |\n| --- | --- |\n| 1 | [링크](http://x.io) |", "||A||B||\n|1|[링크|http://x.io]|"),
+            ("> 인용", "{quote}\n인용\n{quote}"),
+        ];
+        for (markdown, wiki) in cases {
+            assert_eq!(markdown_to_wiki(markdown), wiki, "입력: {}", markdown);
+        }
+    }
+
+    #[test]
+    fn converts_wiki_to_markdown() {
+        let cases = [
+            ("h2. 제목", "## 제목"),
+            ("*굵게* _기울임_ -취소- +밑줄+ {{code}}", "**굵게** *기울임* ~~취소~~ 밑줄 `code`"),
+            ("well-known 2024-01-01 a*b", "well-known 2024-01-01 a\\*b"),
+            ("[Jira|https://x.com] [https://y.com] [~accountid:abc]", "[Jira](https://x.com) <https://y.com> @abc"),
+            ("!shot.png|thumbnail!", "![](shot.png)"),
+            ("{color:red}빨강{color} 텍스트", "빨강 텍스트"),
+            ("줄\\\\바꿈", "줄\n바꿈"),
+            ("{code:java|title=x}\nint a = 1;\n{code}", "```java\nint a = 1;\n```"),
+            ("{noformat}\nraw *text*\n{noformat}", "```\nraw *text*\n```"),
+            ("* a\n** b\n*# c\n* d", "- a\n  - b\n  1. c\n- d"),
+            ("# one\n# two\n## sub", "1. one\n2. two\n   1. sub"),
+            ("* ☐ 할 일\n* ☑ 완료", "- [ ] 할 일\n- [x] 완료"),
+            ("||H1||H2||\n|a|[b|http://c]|", "| H1 | H2 |\n| --- | --- |\n| a | [b](http://c) |"),
+            ("|x|y|", "|  |  |\n| --- | --- |\n| x | y |"),
+            ("{quote}\nquoted\n\nsecond\n{quote}", "> quoted\n>\n> second"),
+            ("bq. short quote", "> short quote"),
+            ("----", "---"),
+            ("1. 번호 아님", "1\\. 번호 아님"),
+        ];
+        for (wiki, markdown) in cases {
+            assert_eq!(wiki_to_markdown(wiki), markdown, "입력: {}", wiki);
+        }
+    }
+
+    // v2 설명을 읽어 수정 없이 다시 저장해도 위키 마크업이 바뀌지 않아야 함 (이중 이스케이프 방지)
+    #[test]
+    fn wiki_survives_read_and_write() {
+        let cases = [
+            "h1. 제목\n\n본문 *굵게* _기울임_ -취소- {{코드}} 2\\*3 a\\_b \\[x\\]",
+            "[Jira|https://example.com] 링크",
+            "* 하나\n** 둘\n* 셋",
+            "# 첫째\n# 둘째\n#* 하위",
+            "* ☐ 할 일\n* ☑ 완료",
+            "{code:rust}\nfn main() { a[0] }\n{code}",
+            "||A||B||\n|1|[링크|http://x.io]|",
+            "{quote}\n인용 _강조_\n\n둘째 문단\n{quote}\n\n----\n\nh3. 소제목",
+            "file\\_name\\_v2 와 snake\\_case",
+        ];
+        for wiki in cases {
+            assert_eq!(markdown_to_wiki(&wiki_to_markdown(wiki)), wiki);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+
+use crate::redact::Redactor;
+use crate::{AIConfig, IssueAnalysis};
+
+// 기존 이슈 초안을 후속 지시("더 짧게", "재현 절차 추가" 등)에 따라 수정합니다.
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefinedAnalysis {
+    pub analysis: IssueAnalysis,
+    pub changes: Vec<FieldChange>,
+}
+
+fn analysis_fields(analysis: &IssueAnalysis) -> Vec<(&'static str, serde_json::Value)> {
+    vec![
+        ("title", serde_json::json!(analysis.title)),
+        ("description", serde_json::json!(analysis.description)),
+        ("issue_type", serde_json::json!(analysis.issue_type)),
+        ("priority", serde_json::json!(analysis.priority)),
+        ("labels", serde_json::json!(analysis.labels)),
+        ("sections", serde_json::json!(analysis.sections.clone().unwrap_or_default())),
+    ]
+}
+
+pub fn build_request(
+    analysis: &IssueAnalysis,
+    instruction: &str,
+    redactor: &mut Redactor,
+    config: &AIConfig,
+) -> serde_json::Value {
+    let draft: serde_json::Map<String, serde_json::Value> = analysis_fields(analysis)
+        .into_iter()
+        .map(|(field, value)| (field.to_string(), value))
+        .collect();
+    let draft = redactor.redact(&serde_json::to_string_pretty(&draft).unwrap_or_default());
+    let instruction = redactor.redact(instruction);
+
+    let mut prompt = format!(
+        "다음 Jira 이슈 초안을 사용자의 지시에 따라 수정해주세요.\n\n\
+        현재 초안:\n{}\n\n\
+        수정 지시: {}\n\n\
+        규칙:\n\
+        - 지시와 관련 없는 필드는 그대로 유지\n\
+        - issue_type은 Bug|Task|Story|Epic 중 하나, priority는 Low|Medium|High|Critical 중 하나\n\
+        - 현재 초안과 동일한 JSON 형식(title, description, issue_type, priority, labels, sections)으로만 응답",
+        draft, instruction
+    );
+
+    if redactor.has_redactions() {
+        prompt.push_str("\n- [REDACTED_...] 형태의 자리표시자는 수정하지 말고 그대로 유지");
+    }
+
+    serde_json::json!({
+        "contents": [
+            {
+                "parts": [
+                    {
+                        "text": format!("당신은 Jira 이슈 관리 전문가입니다.\n\n{}", prompt)
+                    }
+                ]
+            }
+        ],
+        "generationConfig": {
+            "temperature": config.temperature,
+            "maxOutputTokens": 4000
+        }
+    })
+}
+
+// 필드 단위 변경 내역
+pub fn diff(before: &IssueAnalysis, after: &IssueAnalysis) -> Vec<FieldChange> {
+    analysis_fields(before)
+        .into_iter()
+        .zip(analysis_fields(after))
+        .filter(|((_, old), (_, new))| old != new)
+        .map(|((field, old), (_, new))| FieldChange {
+            field: field.to_string(),
+            before: old,
+            after: new,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::IssueSections;
+    use serde_json::json;
+
+    fn draft() -> IssueAnalysis {
+        IssueAnalysis {
+            title: "로그인 실패".to_string(),
+            description: "비밀번호 입력 후 오류".to_string(),
+            issue_type: "Bug".to_string(),
+            priority: "High".to_string(),
+            labels: vec!["auth".to_string(), "web".to_string()],
+            confidence: Some(0.8),
+            sections: None,
+        }
+    }
+
+    fn changed_fields(after: &IssueAnalysis) -> Vec<String> {
+        diff(&draft(), after).into_iter().map(|c| c.field).collect()
+    }
+
+    #[test]
+    fn reports_no_changes_for_same_draft() {
+        assert!(diff(&draft(), &draft()).is_empty());
+
+        // 신뢰도는 비교 대상이 아니고, 빈 섹션은 섹션 없음과 같음
+        let mut after = draft();
+        after.confidence = Some(0.3);
+        after.sections = Some(IssueSections::default());
+        assert!(diff(&draft(), &after).is_empty());
+    }
+
+    #[test]
+    fn reports_changed_title() {
+        let mut after = draft();
+        after.title = "모바일 로그인 실패".to_string();
+
+        let changes = diff(&draft(), &after);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "title");
+        assert_eq!(changes[0].before, json!("로그인 실패"));
+        assert_eq!(changes[0].after, json!("모바일 로그인 실패"));
+    }
+
+    #[test]
+    fn reports_label_additions_and_removals() {
+        let cases: &[&[&str]] = &[&["auth", "web", "mobile"], &["auth"], &["web", "auth"], &[]];
+        for labels in cases {
+            let mut after = draft();
+            after.labels = labels.iter().map(|l| l.to_string()).collect();
+
+            let changes = diff(&draft(), &after);
+            assert_eq!(changes.len(), 1, "입력: {:?}", labels);
+            assert_eq!(changes[0].field, "labels");
+            assert_eq!(changes[0].before, json!(["auth", "web"]));
+            assert_eq!(changes[0].after, json!(labels));
+        }
+    }
+
+    #[test]
+    fn reports_section_changes() {
+        let mut after = draft();
+        after.priority = "Critical".to_string();
+        after.sections = Some(IssueSections {
+            steps_to_reproduce: vec!["로그인 화면 열기".to_string(), "잘못된 비밀번호 입력".to_string()],
+            ..Default::default()
+        });
+        assert_eq!(changed_fields(&after), vec!["priority", "sections"]);
+
+        let change = diff(&draft(), &after).pop().unwrap();
+        assert_eq!(change.before["steps_to_reproduce"], json!([]));
+        assert_eq!(change.after["steps_to_reproduce"], json!(["로그인 화면 열기", "잘못된 비밀번호 입력"]));
+    }
+}
@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+use crate::markdown_adf::local_id;
+
+// Bug/Story 워크플로에서 요구하는 이슈 본문 섹션
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueSections {
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub steps_to_reproduce: Vec<String>,
+    #[serde(default)]
+    pub expected_result: String,
+    #[serde(default)]
+    pub actual_result: String,
+    #[serde(default)]
+    pub acceptance_criteria: Vec<String>,
+    #[serde(default)]
+    pub environment: String,
+}
+
+const SUMMARY_HEADING: &str = "요약";
+const STEPS_HEADING: &str = "재현 절차";
+const EXPECTED_HEADING: &str = "기대 결과";
+const ACTUAL_HEADING: &str = "실제 결과";
+const ACCEPTANCE_HEADING: &str = "인수 조건";
+const ENVIRONMENT_HEADING: &str = "환경";
+
+impl IssueSections {
+    // 모든 텍스트 값에 같은 변환을 적용 (마스킹 복원 등)
+    pub fn map_text(&self, f: impl Fn(&str) -> String) -> Self {
+        Self {
+            summary: f(&self.summary),
+            steps_to_reproduce: self.steps_to_reproduce.iter().map(|s| f(s)).collect(),
+            expected_result: f(&self.expected_result),
+            actual_result: f(&self.actual_result),
+            acceptance_criteria: self.acceptance_criteria.iter().map(|s| f(s)).collect(),
+            environment: f(&self.environment),
+        }
+    }
+
+    // 렌더링할 내용이 없으면 (공백만 있는 값 포함) 비어 있음
+    pub fn is_empty(&self) -> bool {
+        [&self.summary, &self.expected_result, &self.actual_result, &self.environment]
+            .iter()
+            .all(|text| text.trim().is_empty())
+            && self
+                .steps_to_reproduce
+                .iter()
+                .chain(&self.acceptance_criteria)
+                .all(|item| item.trim().is_empty())
+    }
+
+    // Jira 설명(ADF)에 이어붙일 노드 목록
+    pub fn to_adf_nodes(&self) -> Vec<serde_json::Value> {
+        let mut nodes = Vec::new();
+
+        push_paragraph_section(&mut nodes, SUMMARY_HEADING, &self.summary);
+
+        let steps: Vec<&String> = self.steps_to_reproduce.iter().filter(|s| !s.trim().is_empty()).collect();
+        if !steps.is_empty() {
+            nodes.push(heading(STEPS_HEADING));
+            nodes.push(serde_json::json!({
+                "type": "orderedList",
+                "content": steps
+                    .iter()
+                    .map(|step| serde_json::json!({
+                        "type": "listItem",
+                        "content": [paragraph(step)]
+                    }))
+                    .collect::<Vec<_>>()
+            }));
+        }
+
+        push_paragraph_section(&mut nodes, EXPECTED_HEADING, &self.expected_result);
+        push_paragraph_section(&mut nodes, ACTUAL_HEADING, &self.actual_result);
+
+        let criteria: Vec<&String> = self.acceptance_criteria.iter().filter(|s| !s.trim().is_empty()).collect();
+        if !criteria.is_empty() {
+            nodes.push(heading(ACCEPTANCE_HEADING));
+            nodes.push(serde_json::json!({
+                "type": "taskList",
+                "attrs": { "localId": local_id() },
+                "content": criteria
+                    .iter()
+                    .map(|item| serde_json::json!({
+                        "type": "taskItem",
+                        "attrs": { "localId": local_id(), "state": "TODO" },
+                        "content": [{ "type": "text", "text": item.trim() }]
+                    }))
+                    .collect::<Vec<_>>()
+            }));
+        }
+
+        push_paragraph_section(&mut nodes, ENVIRONMENT_HEADING, &self.environment);
+
+        nodes
+    }
+
+    // 같은 섹션을 Markdown으로 (v2 위키 마크업 변환용)
+    pub fn to_markdown(&self) -> String {
+        let mut blocks = Vec::new();
+
+        push_markdown_section(&mut blocks, SUMMARY_HEADING, &self.summary);
+
+        let steps: Vec<String> = self
+            .steps_to_reproduce
+            .iter()
+            .filter(|s| !s.trim().is_empty())
+            .enumerate()
+            .map(|(i, step)| format!("{}. {}", i + 1, step.trim()))
+            .collect();
+        if !steps.is_empty() {
+            blocks.push(format!("### {}\n\n{}", STEPS_HEADING, steps.join("\n")));
+        }
+
+        push_markdown_section(&mut blocks, EXPECTED_HEADING, &self.expected_result);
+        push_markdown_section(&mut blocks, ACTUAL_HEADING, &self.actual_result);
+
+        let criteria: Vec<String> = self
+            .acceptance_criteria
+            .iter()
+            .filter(|s| !s.trim().is_empty())
+            .map(|item| format!("- [ ] {}", item.trim()))
+            .collect();
+        if !criteria.is_empty() {
+            blocks.push(format!("### {}\n\n{}", ACCEPTANCE_HEADING, criteria.join("\n")));
+        }
+
+        push_markdown_section(&mut blocks, ENVIRONMENT_HEADING, &self.environment);
+
+        blocks.join("\n\n")
+    }
+}
+
+fn push_markdown_section(blocks: &mut Vec<String>, title: &str, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+    blocks.push(format!("### {}\n\n{}", title, text.trim()));
+}
+
+fn push_paragraph_section(nodes: &mut Vec<serde_json::Value>, title: &str, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+    nodes.push(heading(title));
+    nodes.push(paragraph(text));
+}
+
+fn heading(text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "type": "heading",
+        "attrs": { "level": 3 },
+        "content": [{ "type": "text", "text": text }]
+    })
+}
+
+fn paragraph(text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "type": "paragraph",
+        "content": [{ "type": "text", "text": text.trim() }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blank_sections_are_empty() {
+        assert!(IssueSections::default().is_empty());
+        let blank = IssueSections {
+            summary: "  ".to_string(),
+            steps_to_reproduce: vec![String::new()],
+            ..Default::default()
+        };
+        assert!(blank.is_empty());
+        assert!(blank.to_adf_nodes().is_empty());
+        assert!(blank.to_markdown().is_empty());
+
+        let filled = IssueSections {
+            acceptance_criteria: vec!["저장된다".to_string()],
+            ..Default::default()
+        };
+        assert!(!filled.is_empty());
+    }
+
+    #[test]
+    fn renders_markdown_sections() {
+        let sections = IssueSections {
+            summary: "로그인 실패".to_string(),
+            steps_to_reproduce: vec!["앱 실행".to_string(), " ".to_string(), "로그인".to_string()],
+            acceptance_criteria: vec!["오류 없이 로그인".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            sections.to_markdown(),
+            "### 요약\n\n로그인 실패\n\n### 재현 절차\n\n1. 앱 실행\n2. 로그인\n\n### 인수 조건\n\n- [ ] 오류 없이 로그인"
+        );
+    }
+}
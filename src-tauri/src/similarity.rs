@@ -0,0 +1,25 @@
+use std::collections::HashSet;
+
+// 문자 바이그램 집합 (띄어쓰기가 일정하지 않은 한국어에서도 동작)
+pub fn bigrams(text: &str) -> HashSet<(char, char)> {
+    let chars: Vec<char> = text
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+// 바이그램 집합의 자카드 유사도 (0.0 ~ 1.0)
+pub fn jaccard(a: &HashSet<(char, char)>, b: &HashSet<(char, char)>) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let intersection = a.intersection(b).count();
+    let union = a.len() + b.len() - intersection;
+    intersection as f32 / union as f32
+}
+
+pub fn text_similarity(a: &str, b: &str) -> f32 {
+    jaccard(&bigrams(a), &bigrams(b))
+}